@@ -126,6 +126,21 @@ impl<'a> Blob<'a> {
         self.len() == 0
     }
 
+    /// Returns the number of faces in the blob.
+    ///
+    /// This is greater than one for font collections such as TTC and DFont
+    /// files, and zero if the blob does not hold a known font format.
+    ///
+    /// ```
+    /// # use harfbuzz::Blob;
+    /// let blob = Blob::new_from_file("../harfbuzz-sys/harfbuzz/test/shape/data/in-house/fonts/TTC.ttc").unwrap();
+    /// assert_eq!(blob.face_count(), 2);
+    /// assert_eq!(Blob::new_read_only(&[0; 16]).face_count(), 0);
+    /// ```
+    pub fn face_count(&self) -> u32 {
+        unsafe { sys::hb_face_count(self.raw) }
+    }
+
     /// Make this blob immutable.
     pub fn make_immutable(&mut self) {
         unsafe {
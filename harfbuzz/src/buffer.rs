@@ -8,6 +8,7 @@
 // except according to those terms.
 
 use std;
+use std::ops::{Bound, Range, RangeBounds};
 use bitflags::bitflags;
 use sys;
 
@@ -380,6 +381,121 @@ impl Buffer {
         }
     }
 
+    /// Maps a byte range of the text added with [`add_str`] to the range
+    /// of cluster values that HarfBuzz matches feature ranges against.
+    ///
+    /// With UTF-8 input, cluster values are byte offsets into the text.
+    /// The start of the range is moved back to the start of the cluster
+    /// containing it: the character for the `CHARACTERS` and
+    /// `MONOTONE_CHARACTERS` cluster levels, the grapheme for
+    /// `MONOTONE_GRAPHEMES`, where HarfBuzz assigns every character of a
+    /// grapheme the cluster value of its first character. The result can
+    /// be passed to [`Feature::enable_for_range`].
+    ///
+    /// The cluster level must be set before calling this method.
+    ///
+    /// At the `MONOTONE_GRAPHEMES` level, each call shapes a copy of the
+    /// buffer to find the graphemes, which takes time linear in the length
+    /// of the buffer. Use [`cluster_ranges`] to map many byte ranges at
+    /// once.
+    ///
+    /// ```
+    /// # use harfbuzz::{Buffer, Feature, Tag};
+    /// let text = "Cafe\u{301} society";
+    /// let b = Buffer::with(text);
+    /// // The span starts on the combining acute accent, which belongs
+    /// // to the grapheme starting at the 'e'.
+    /// let range = b.cluster_range(4..text.len());
+    /// assert_eq!(range, 3..text.len() as u32);
+    /// let smcp = Feature::enable_for_range(Tag::from_string("smcp"), range);
+    /// assert_eq!(smcp.start(), 3);
+    /// ```
+    ///
+    /// [`add_str`]: #method.add_str
+    /// [`cluster_ranges`]: #method.cluster_ranges
+    /// [`Feature::enable_for_range`]: crate::Feature::enable_for_range
+    pub fn cluster_range(&self, bytes: impl RangeBounds<usize>) -> Range<u32> {
+        to_cluster_range(&self.cluster_starts(), bytes)
+    }
+
+    /// Maps several byte ranges of the text to cluster ranges, as
+    /// [`cluster_range`] does, finding the clusters of the buffer only
+    /// once.
+    ///
+    /// ```
+    /// # use harfbuzz::Buffer;
+    /// let text = "Cafe\u{301} society";
+    /// let b = Buffer::with(text);
+    /// let ranges = b.cluster_ranges([0..3, 4..6, 7..text.len()]);
+    /// assert_eq!(ranges, [0..3, 3..6, 7..text.len() as u32]);
+    /// ```
+    ///
+    /// [`cluster_range`]: #method.cluster_range
+    pub fn cluster_ranges<R: RangeBounds<usize>>(&self, ranges: impl IntoIterator<Item = R>) -> Vec<Range<u32>> {
+        let starts = self.cluster_starts();
+        ranges.into_iter().map(|bytes| to_cluster_range(&starts, bytes)).collect()
+    }
+
+    /// Returns the distinct cluster values of the buffer, in ascending
+    /// order, as they are at the buffer's cluster level.
+    fn cluster_starts(&self) -> Vec<u32> {
+        // At the grapheme level, let HarfBuzz form the clusters: shaping a
+        // copy of the text with the empty font merges the cluster values of
+        // each grapheme, without needing the glyphs of a real font.
+        let graphemes = self.get_cluster_level() == BufferClusterLevel::MONOTONE_GRAPHEMES
+            && unsafe { sys::hb_buffer_get_content_type(self.raw) } == sys::HB_BUFFER_CONTENT_TYPE_UNICODE;
+        let shaped = graphemes.then(|| unsafe {
+            let shaped = Buffer::from_raw(sys::hb_buffer_create_similar(self.raw));
+            sys::hb_buffer_append(shaped.raw, self.raw, 0, u32::MAX);
+            sys::hb_buffer_guess_segment_properties(shaped.raw);
+            // The inert `hb_font_get_empty` font does not get shaped with,
+            // so create a font for an empty face.
+            let face = sys::hb_face_create(sys::hb_blob_get_empty(), 0);
+            let font = sys::hb_font_create(face);
+            sys::hb_shape(font, shaped.raw, std::ptr::null(), 0);
+            sys::hb_font_destroy(font);
+            sys::hb_face_destroy(face);
+            shaped
+        });
+        let infos = unsafe {
+            let raw = shaped.as_ref().map_or(self.raw, |shaped| shaped.raw);
+            let mut length: u32 = 0;
+            let infos = sys::hb_buffer_get_glyph_infos(raw, &mut length);
+            if infos.is_null() {
+                &[][..]
+            } else {
+                std::slice::from_raw_parts(infos, length as usize)
+            }
+        };
+
+        let mut starts: Vec<u32> = infos.iter().map(|info| info.cluster).collect();
+        starts.sort_unstable();
+        starts.dedup();
+        starts
+    }
+}
+
+/// Maps a byte range to a cluster range, moving its start back to the
+/// start of the cluster containing it, given the sorted cluster values.
+fn to_cluster_range(starts: &[u32], bytes: impl RangeBounds<usize>) -> Range<u32> {
+    let start = match bytes.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match bytes.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => usize::MAX,
+    };
+    let start = u32::try_from(start).unwrap_or(u32::MAX);
+    let end = u32::try_from(end).unwrap_or(u32::MAX);
+
+    let cluster_start = match starts.partition_point(|&cluster| cluster <= start) {
+        0 => start,
+        index => starts[index - 1],
+    };
+    cluster_start..end.max(cluster_start)
 }
 
 impl std::fmt::Debug for Buffer {
@@ -465,3 +581,19 @@ bitflags! {
         const DEFAULT = sys::HB_BUFFER_CLUSTER_LEVEL_DEFAULT;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Buffer;
+
+    #[test]
+    fn test_cluster_range_after_zwj() {
+        // Both are Extended_Pictographic, so they join the grapheme of
+        // the ZWJ before them.
+        for text in ["a\u{200D}\u{A9}b", "a\u{200D}\u{2122}b"] {
+            let b = Buffer::with(text);
+            assert_eq!(b.cluster_range(4..), 0..u32::MAX);
+            assert_eq!(b.cluster_range(text.len() - 1..), text.len() as u32 - 1..u32::MAX);
+        }
+    }
+}
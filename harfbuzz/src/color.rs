@@ -0,0 +1,179 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use bitflags::bitflags;
+use std::marker::PhantomData;
+use sys;
+
+use crate::paging::Pages;
+use crate::{Blob, GlyphExtents, NameId};
+
+/// A color with 8-bit red, green, blue and alpha channels.
+///
+/// This maps to the [`hb_color_t`] from [`harfbuzz-sys`], which packs the
+/// channels as BGRA, and can be converted to or from it using the
+/// [`From`] and [`Into`] traits:
+///
+/// ```
+/// # use harfbuzz::{sys, Color};
+/// let color = Color::new(0x12, 0x34, 0x56, 0xff);
+/// assert_eq!(color.red(), 0x12);
+/// assert_eq!(color.alpha(), 0xff);
+/// assert_eq!(sys::hb_color_t::from(color), 0x563412ff);
+/// assert_eq!(Color::from(0x563412ff), color);
+/// ```
+///
+/// [`hb_color_t`]: ../harfbuzz_sys/type.hb_color_t.html
+/// [`harfbuzz-sys`]: ../harfbuzz_sys/index.html
+/// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+/// [`Into`]: https://doc.rust-lang.org/std/convert/trait.Into.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Color(sys::hb_color_t);
+
+impl Color {
+    /// Creates a color from its channels.
+    pub fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Color {
+        Color(u32::from_be_bytes([blue, green, red, alpha]))
+    }
+
+    /// The red channel of the color.
+    pub fn red(&self) -> u8 {
+        unsafe { sys::hb_color_get_red(self.0) }
+    }
+
+    /// The green channel of the color.
+    pub fn green(&self) -> u8 {
+        unsafe { sys::hb_color_get_green(self.0) }
+    }
+
+    /// The blue channel of the color.
+    pub fn blue(&self) -> u8 {
+        unsafe { sys::hb_color_get_blue(self.0) }
+    }
+
+    /// The alpha channel of the color.
+    pub fn alpha(&self) -> u8 {
+        unsafe { sys::hb_color_get_alpha(self.0) }
+    }
+}
+
+impl From<sys::hb_color_t> for Color {
+    fn from(color: sys::hb_color_t) -> Self {
+        Color(color)
+    }
+}
+
+impl From<Color> for sys::hb_color_t {
+    fn from(color: Color) -> Self {
+        color.0
+    }
+}
+
+/// A color palette of a color font, from its `CPAL` table.
+///
+/// See [`Face::color_palettes`](crate::Face::color_palettes).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorPalette {
+    /// The index of the palette in the font's `CPAL` table.
+    pub index: u32,
+    /// The flags of the palette.
+    pub flags: PaletteFlags,
+    /// The `name` table ID of the palette's name, if any.
+    pub name_id: Option<NameId>,
+    /// The colors of the palette.
+    pub colors: Vec<Color>,
+}
+
+bitflags! {
+    /// Flags of a color palette.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct PaletteFlags: u32 {
+        /// The palette is appropriate for a light background.
+        const USABLE_WITH_LIGHT_BACKGROUND = sys::HB_OT_COLOR_PALETTE_FLAG_USABLE_WITH_LIGHT_BACKGROUND;
+        /// The palette is appropriate for a dark background.
+        const USABLE_WITH_DARK_BACKGROUND = sys::HB_OT_COLOR_PALETTE_FLAG_USABLE_WITH_DARK_BACKGROUND;
+    }
+}
+
+/// A layer of a `COLR` version 0 color glyph.
+///
+/// See [`Face::color_layers`](crate::Face::color_layers).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ColorLayer {
+    /// The glyph whose outline is the shape of the layer.
+    pub glyph: u32,
+    /// The index of the color of the layer in the color palettes, or
+    /// `0xFFFF` for the foreground color.
+    pub color_index: u32,
+}
+
+impl ColorLayer {
+    /// Returns true if the layer is painted with the foreground color
+    /// instead of a palette color.
+    pub fn is_foreground(&self) -> bool {
+        self.color_index == 0xFFFF
+    }
+}
+
+impl From<sys::hb_ot_color_layer_t> for ColorLayer {
+    fn from(layer: sys::hb_ot_color_layer_t) -> Self {
+        ColorLayer {
+            glyph: layer.glyph,
+            color_index: layer.color_index,
+        }
+    }
+}
+
+/// An iterator over the layers of a color glyph, fetching them from
+/// HarfBuzz a page at a time.
+///
+/// See [`Face::color_layers_iter`](crate::Face::color_layers_iter).
+pub struct ColorLayers<'a> {
+    face: *mut sys::hb_face_t,
+    glyph: u32,
+    pages: Pages<sys::hb_ot_color_layer_t>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> ColorLayers<'a> {
+    pub(crate) fn new(face: *mut sys::hb_face_t, glyph: u32) -> Self {
+        ColorLayers {
+            face,
+            glyph,
+            pages: Pages::new(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a> Iterator for ColorLayers<'a> {
+    type Item = ColorLayer;
+
+    fn next(&mut self) -> Option<ColorLayer> {
+        let (face, glyph) = (self.face, self.glyph);
+        let layer = unsafe {
+            self.pages.next(|offset, count, layers| {
+                sys::hb_ot_color_glyph_get_layers(face, glyph, offset, count, layers);
+            })
+        };
+        layer.map(ColorLayer::from)
+    }
+}
+
+/// A color glyph stored as a PNG image, from the `CBDT` or `sbix` tables.
+///
+/// See [`Font::get_glyph_png`](crate::Font::get_glyph_png).
+#[derive(Clone)]
+pub struct PngGlyph<'a> {
+    /// The PNG image data, borrowed from the font data.
+    pub data: Blob<'a>,
+    /// The extents of the glyph, which the image should fill, in font
+    /// units scaled by the font's scale.
+    pub extents: Option<GlyphExtents>,
+}
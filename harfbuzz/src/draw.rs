@@ -0,0 +1,401 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::os::raw::c_void;
+use std::sync::OnceLock;
+use std::{mem, ptr};
+use sys;
+
+/// A receiver of glyph outlines.
+///
+/// Outlines are described as a sequence of contours, each starting with
+/// `move_to` and ending with `close_path`. Coordinates are in font
+/// units scaled by the font's scale, with the y axis pointing up.
+pub trait DrawSink {
+    /// Starts a new contour at the given point.
+    fn move_to(&mut self, x: f32, y: f32);
+
+    /// Adds a straight line from the current point to the given point.
+    fn line_to(&mut self, x: f32, y: f32);
+
+    /// Adds a quadratic Bézier curve from the current point to the
+    /// given point, with the given control point.
+    fn quad_to(&mut self, control_x: f32, control_y: f32, x: f32, y: f32);
+
+    /// Adds a cubic Bézier curve from the current point to the given
+    /// point, with the given control points.
+    fn cubic_to(
+        &mut self,
+        control1_x: f32,
+        control1_y: f32,
+        control2_x: f32,
+        control2_y: f32,
+        x: f32,
+        y: f32,
+    );
+
+    /// Closes the current contour.
+    fn close_path(&mut self);
+}
+
+/// A command of an [`OutlinePath`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathCommand {
+    /// Starts a new contour at the given point.
+    MoveTo {
+        /// The x coordinate of the point.
+        x: f32,
+        /// The y coordinate of the point.
+        y: f32,
+    },
+    /// Adds a straight line to the given point.
+    LineTo {
+        /// The x coordinate of the end point.
+        x: f32,
+        /// The y coordinate of the end point.
+        y: f32,
+    },
+    /// Adds a quadratic Bézier curve to the given point.
+    QuadTo {
+        /// The x coordinate of the control point.
+        control_x: f32,
+        /// The y coordinate of the control point.
+        control_y: f32,
+        /// The x coordinate of the end point.
+        x: f32,
+        /// The y coordinate of the end point.
+        y: f32,
+    },
+    /// Adds a cubic Bézier curve to the given point.
+    CubicTo {
+        /// The x coordinate of the first control point.
+        control1_x: f32,
+        /// The y coordinate of the first control point.
+        control1_y: f32,
+        /// The x coordinate of the second control point.
+        control2_x: f32,
+        /// The y coordinate of the second control point.
+        control2_y: f32,
+        /// The x coordinate of the end point.
+        x: f32,
+        /// The y coordinate of the end point.
+        y: f32,
+    },
+    /// Closes the current contour.
+    ClosePath,
+}
+
+/// A `DrawSink` collecting outlines as a list of path commands.
+///
+/// ```
+/// # use harfbuzz::{Face, Font, OutlinePath, PathCommand};
+/// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+/// let font = Font::new(&face);
+/// let mut path = OutlinePath::new();
+/// font.draw_glyph(font.get_nominal_glyph('o').unwrap(), &mut path);
+///
+/// let contours = path.commands().iter().filter(|command| **command == PathCommand::ClosePath).count();
+/// assert_eq!(contours, 2);
+/// assert!(matches!(path.commands()[0], PathCommand::MoveTo { .. }));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OutlinePath {
+    commands: Vec<PathCommand>,
+}
+
+impl OutlinePath {
+    /// Creates an empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The commands of the path, in drawing order.
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    /// Consumes the path, returning its commands.
+    pub fn into_commands(self) -> Vec<PathCommand> {
+        self.commands
+    }
+
+    /// Returns true if the path has no commands.
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Removes all commands from the path, keeping its allocation.
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+}
+
+impl DrawSink for OutlinePath {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.commands.push(PathCommand::MoveTo { x, y });
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.commands.push(PathCommand::LineTo { x, y });
+    }
+
+    fn quad_to(&mut self, control_x: f32, control_y: f32, x: f32, y: f32) {
+        self.commands.push(PathCommand::QuadTo {
+            control_x,
+            control_y,
+            x,
+            y,
+        });
+    }
+
+    fn cubic_to(
+        &mut self,
+        control1_x: f32,
+        control1_y: f32,
+        control2_x: f32,
+        control2_y: f32,
+        x: f32,
+        y: f32,
+    ) {
+        self.commands.push(PathCommand::CubicTo {
+            control1_x,
+            control1_y,
+            control2_x,
+            control2_y,
+            x,
+            y,
+        });
+    }
+
+    fn close_path(&mut self) {
+        self.commands.push(PathCommand::ClosePath);
+    }
+}
+
+/// A `DrawSink` forwarding to HarfBuzz draw functions, the way the
+/// built-in font functions emit outlines.
+pub(crate) struct HbDrawSink {
+    funcs: *mut sys::hb_draw_funcs_t,
+    data: *mut c_void,
+    state: sys::hb_draw_state_t,
+}
+
+impl HbDrawSink {
+    pub(crate) fn new(funcs: *mut sys::hb_draw_funcs_t, data: *mut c_void) -> Self {
+        HbDrawSink {
+            funcs,
+            data,
+            // All zeros is `HB_DRAW_STATE_DEFAULT`.
+            state: unsafe { mem::zeroed() },
+        }
+    }
+}
+
+impl DrawSink for HbDrawSink {
+    fn move_to(&mut self, x: f32, y: f32) {
+        unsafe { sys::hb_draw_move_to(self.funcs, self.data, &mut self.state, x, y) }
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        unsafe { sys::hb_draw_line_to(self.funcs, self.data, &mut self.state, x, y) }
+    }
+
+    fn quad_to(&mut self, control_x: f32, control_y: f32, x: f32, y: f32) {
+        unsafe {
+            sys::hb_draw_quadratic_to(self.funcs, self.data, &mut self.state, control_x, control_y, x, y)
+        }
+    }
+
+    fn cubic_to(
+        &mut self,
+        control1_x: f32,
+        control1_y: f32,
+        control2_x: f32,
+        control2_y: f32,
+        x: f32,
+        y: f32,
+    ) {
+        unsafe {
+            sys::hb_draw_cubic_to(
+                self.funcs,
+                self.data,
+                &mut self.state,
+                control1_x,
+                control1_y,
+                control2_x,
+                control2_y,
+                x,
+                y,
+            )
+        }
+    }
+
+    fn close_path(&mut self) {
+        unsafe { sys::hb_draw_close_path(self.funcs, self.data, &mut self.state) }
+    }
+}
+
+impl Drop for HbDrawSink {
+    /// Closes the last contour if it was left open.
+    fn drop(&mut self) {
+        if self.state.path_open != 0 {
+            self.close_path();
+        }
+    }
+}
+
+/// A `DrawSink` scaling, slanting and translating outlines before drawing
+/// them into another sink, mapping (x, y) to (`x_scale` * x + `slant` * y
+/// + `dx`, `y_scale` * y + `dy`).
+pub(crate) struct TransformSink<'a> {
+    pub(crate) sink: &'a mut dyn DrawSink,
+    pub(crate) x_scale: f32,
+    pub(crate) y_scale: f32,
+    pub(crate) slant: f32,
+    pub(crate) dx: f32,
+    pub(crate) dy: f32,
+}
+
+impl<'a> TransformSink<'a> {
+    fn transform(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.x_scale * x + self.slant * y + self.dx, self.y_scale * y + self.dy)
+    }
+}
+
+impl<'a> DrawSink for TransformSink<'a> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.transform(x, y);
+        self.sink.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.transform(x, y);
+        self.sink.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, control_x: f32, control_y: f32, x: f32, y: f32) {
+        let (control_x, control_y) = self.transform(control_x, control_y);
+        let (x, y) = self.transform(x, y);
+        self.sink.quad_to(control_x, control_y, x, y);
+    }
+
+    fn cubic_to(
+        &mut self,
+        control1_x: f32,
+        control1_y: f32,
+        control2_x: f32,
+        control2_y: f32,
+        x: f32,
+        y: f32,
+    ) {
+        let (control1_x, control1_y) = self.transform(control1_x, control1_y);
+        let (control2_x, control2_y) = self.transform(control2_x, control2_y);
+        let (x, y) = self.transform(x, y);
+        self.sink.cubic_to(control1_x, control1_y, control2_x, control2_y, x, y);
+    }
+
+    fn close_path(&mut self) {
+        self.sink.close_path();
+    }
+}
+
+/// Draws `glyph` of the raw `font` into `sink`.
+pub(crate) fn draw_glyph(font: *mut sys::hb_font_t, glyph: u32, sink: &mut dyn DrawSink) {
+    let mut sink = sink;
+    unsafe {
+        sys::hb_font_draw_glyph(font, glyph, draw_funcs(), &mut sink as *mut &mut dyn DrawSink as *mut c_void);
+    }
+}
+
+/// Shared draw functions forwarding to a `&mut dyn DrawSink`, passed by
+/// pointer as the draw data.
+fn draw_funcs() -> *mut sys::hb_draw_funcs_t {
+    struct DrawFuncs(*mut sys::hb_draw_funcs_t);
+    // The draw functions are immutable once created.
+    unsafe impl Send for DrawFuncs {}
+    unsafe impl Sync for DrawFuncs {}
+
+    static DRAW_FUNCS: OnceLock<DrawFuncs> = OnceLock::new();
+    DRAW_FUNCS
+        .get_or_init(|| unsafe {
+            let funcs = sys::hb_draw_funcs_create();
+            sys::hb_draw_funcs_set_move_to_func(funcs, Some(move_to), ptr::null_mut(), None);
+            sys::hb_draw_funcs_set_line_to_func(funcs, Some(line_to), ptr::null_mut(), None);
+            sys::hb_draw_funcs_set_quadratic_to_func(funcs, Some(quadratic_to), ptr::null_mut(), None);
+            sys::hb_draw_funcs_set_cubic_to_func(funcs, Some(cubic_to), ptr::null_mut(), None);
+            sys::hb_draw_funcs_set_close_path_func(funcs, Some(close_path), ptr::null_mut(), None);
+            sys::hb_draw_funcs_make_immutable(funcs);
+            DrawFuncs(funcs)
+        })
+        .0
+}
+
+unsafe fn sink<'a>(draw_data: *mut c_void) -> &'a mut dyn DrawSink {
+    *(draw_data as *mut &mut dyn DrawSink)
+}
+
+unsafe extern "C" fn move_to(
+    _dfuncs: *mut sys::hb_draw_funcs_t,
+    draw_data: *mut c_void,
+    _st: *mut sys::hb_draw_state_t,
+    to_x: f32,
+    to_y: f32,
+    _user_data: *mut c_void,
+) {
+    sink(draw_data).move_to(to_x, to_y);
+}
+
+unsafe extern "C" fn line_to(
+    _dfuncs: *mut sys::hb_draw_funcs_t,
+    draw_data: *mut c_void,
+    _st: *mut sys::hb_draw_state_t,
+    to_x: f32,
+    to_y: f32,
+    _user_data: *mut c_void,
+) {
+    sink(draw_data).line_to(to_x, to_y);
+}
+
+unsafe extern "C" fn quadratic_to(
+    _dfuncs: *mut sys::hb_draw_funcs_t,
+    draw_data: *mut c_void,
+    _st: *mut sys::hb_draw_state_t,
+    control_x: f32,
+    control_y: f32,
+    to_x: f32,
+    to_y: f32,
+    _user_data: *mut c_void,
+) {
+    sink(draw_data).quad_to(control_x, control_y, to_x, to_y);
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn cubic_to(
+    _dfuncs: *mut sys::hb_draw_funcs_t,
+    draw_data: *mut c_void,
+    _st: *mut sys::hb_draw_state_t,
+    control1_x: f32,
+    control1_y: f32,
+    control2_x: f32,
+    control2_y: f32,
+    to_x: f32,
+    to_y: f32,
+    _user_data: *mut c_void,
+) {
+    sink(draw_data).cubic_to(control1_x, control1_y, control2_x, control2_y, to_x, to_y);
+}
+
+unsafe extern "C" fn close_path(
+    _dfuncs: *mut sys::hb_draw_funcs_t,
+    draw_data: *mut c_void,
+    _st: *mut sys::hb_draw_state_t,
+    _user_data: *mut c_void,
+) {
+    sink(draw_data).close_path();
+}
@@ -7,9 +7,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::Blob;
+use super::{
+    AxisInfo, Blob, Color, ColorLayer, ColorLayers, ColorPalette, Language, NameEntry, NameId, NamedInstance,
+    PaletteFlags, Set, Tag, Variation,
+};
+use super::paging::Pages;
+use std::collections::{BTreeSet, HashMap};
 use std::marker::PhantomData;
-use std::mem;
+use std::mem::MaybeUninit;
+use std::os::raw::{c_char, c_uint, c_void};
+use std::{mem, ptr, slice};
 use sys;
 
 /// Face wrap a chunk of binary data to handle lifecycle management of data
@@ -39,6 +46,80 @@ impl<'a> Face<'a> {
         }
     }
 
+    /// Create a new font face whose tables are provided by a closure.
+    ///
+    /// The closure is called with the tag of a table whenever HarfBuzz
+    /// needs its data, and returns `None` if the face has no such table.
+    /// This allows loading tables lazily instead of building a whole font
+    /// file in memory.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use std::sync::Arc;
+    /// # use harfbuzz::{Blob, Face, Tag};
+    /// let source = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let tables: HashMap<Tag, Arc<Vec<u8>>> = source
+    ///     .table_tags()
+    ///     .map(|tag| (tag, Arc::new(source.table(tag).unwrap().to_vec())))
+    ///     .collect();
+    /// let face = Face::new_for_tables(move |tag| tables.get(&tag).cloned().map(Blob::new_from_arc_vec));
+    /// assert_eq!(face.get_upem(), 1000);
+    /// assert_eq!(face.get_glyph_count(), 1942);
+    /// assert!(face.table(Tag::from_string("glyf")).is_none());
+    /// ```
+    pub fn new_for_tables<F>(reference_table: F) -> Face<'static>
+    where
+        F: Fn(Tag) -> Option<Blob<'static>> + Send + Sync + 'static,
+    {
+        // This has type hb_reference_table_func_t
+        unsafe extern "C" fn reference_table_func<F>(
+            _face: *mut sys::hb_face_t,
+            tag: sys::hb_tag_t,
+            user_data: *mut c_void,
+        ) -> *mut sys::hb_blob_t
+        where
+            F: Fn(Tag) -> Option<Blob<'static>>,
+        {
+            let reference_table = &*(user_data as *const F);
+            match reference_table(tag.into()) {
+                Some(blob) => blob.into_raw(),
+                None => ptr::null_mut(),
+            }
+        }
+
+        // This has type hb_destroy_func_t
+        unsafe extern "C" fn destroy_func<F>(user_data: *mut c_void) {
+            drop(Box::from_raw(user_data as *mut F));
+        }
+
+        unsafe {
+            let user_data = Box::into_raw(Box::new(reference_table));
+            Face::from_raw(sys::hb_face_create_for_tables(
+                Some(reference_table_func::<F>),
+                user_data as *mut c_void,
+                Some(destroy_func::<F>),
+            ))
+        }
+    }
+
+    /// Create a font face for each face of the given blob.
+    ///
+    /// All faces share the blob, so this is the way to load every face of
+    /// a TTC or DFont collection. A blob that does not hold a known font
+    /// format yields no faces.
+    ///
+    /// ```
+    /// # use harfbuzz::{Blob, Face};
+    /// let blob = Blob::new_from_file("../harfbuzz-sys/harfbuzz/test/shape/data/in-house/fonts/TTC.ttc").unwrap();
+    /// let faces: Vec<Face> = Face::all_from_blob(blob).collect();
+    /// assert_eq!(faces.len(), 2);
+    /// assert_eq!(faces[1].get_index(), 1);
+    /// ```
+    pub fn all_from_blob(blob: Blob<'a>) -> impl Iterator<Item = Face<'a>> {
+        let count = blob.face_count();
+        (0..count).map(move |index| unsafe { Face::from_raw(sys::hb_face_create(blob.as_ptr(), index)) })
+    }
+
     /// Create a new font face from the given file and face index into the file.
     ///
     /// The face index is used for files of formats such as TTC and DFont
@@ -92,6 +173,472 @@ impl<'a> Face<'a> {
         unsafe { sys::hb_face_get_glyph_count(self.raw) }
     }
 
+    /// Returns true if the face includes `fvar` variation data.
+    pub fn has_variation_data(&self) -> bool {
+        unsafe { sys::hb_ot_var_has_data(self.raw) != 0 }
+    }
+
+    /// Fetches the number of variation axes in the face.
+    pub fn get_axis_count(&self) -> u32 {
+        unsafe { sys::hb_ot_var_get_axis_count(self.raw) }
+    }
+
+    /// Fetches all the variation axes of the face, in `fvar` order.
+    ///
+    /// ```
+    /// # use harfbuzz::{AxisFlags, Face, Tag};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansVariable-Roman.abc.ttf", 0).unwrap();
+    /// assert!(face.has_variation_data());
+    /// let axes = face.variation_axes();
+    /// assert_eq!(axes.len(), face.get_axis_count() as usize);
+    /// assert_eq!(axes[0].tag, Tag::from_string("wght"));
+    /// assert_eq!((axes[0].min_value, axes[0].max_value), (200.0, 900.0));
+    /// assert!(!axes[0].flags.contains(AxisFlags::HIDDEN));
+    /// ```
+    pub fn variation_axes(&self) -> Vec<AxisInfo> {
+        unsafe {
+            let mut count = sys::hb_ot_var_get_axis_count(self.raw);
+            let mut infos = Vec::with_capacity(count as usize);
+            sys::hb_ot_var_get_axis_infos(self.raw, 0, &mut count, infos.as_mut_ptr());
+            infos.set_len(count as usize);
+            infos.into_iter().map(AxisInfo::from).collect()
+        }
+    }
+
+    /// Fetches the variation axis with the given tag, if the face has one.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Tag};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansVariable-Roman.abc.ttf", 0).unwrap();
+    /// assert!(face.find_axis_info(Tag::from_string("wght")).is_some());
+    /// assert!(face.find_axis_info(Tag::from_string("wdth")).is_none());
+    /// ```
+    pub fn find_axis_info(&self, tag: Tag) -> Option<AxisInfo> {
+        unsafe {
+            let mut info = MaybeUninit::uninit();
+            if sys::hb_ot_var_find_axis_info(self.raw, tag.into(), info.as_mut_ptr()) != 0 {
+                Some(info.assume_init().into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Normalizes a list of variations to coordinates in 2.14 fixed-point
+    /// format, taking the `avar` table into account.
+    ///
+    /// The returned list has one coordinate per axis of the face, in
+    /// `fvar` order. Axes not mentioned in `variations` are left at their
+    /// default (zero).
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font, Variation};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansVariable-Roman.abc.ttf", 0).unwrap();
+    /// let variations = ["wght=700".parse::<Variation>().unwrap()];
+    /// let coords = face.normalize_variations(&variations);
+    /// assert_eq!(coords, vec![11821, 0]);
+    ///
+    /// let mut font = Font::new(&face);
+    /// font.set_variations(&variations);
+    /// assert_eq!(font.get_var_coords_normalized(), &coords[..]);
+    /// ```
+    pub fn normalize_variations(&self, variations: &[Variation]) -> Vec<i32> {
+        let mut coords = vec![0; self.get_axis_count() as usize];
+        unsafe {
+            sys::hb_ot_var_normalize_variations(
+                self.raw,
+                variations.as_ptr() as *const sys::hb_variation_t,
+                variations.len() as c_uint,
+                coords.as_mut_ptr(),
+                coords.len() as c_uint,
+            );
+        }
+        coords
+    }
+
+    /// Normalizes a list of design-space coordinates, given in `fvar`
+    /// order, to coordinates in 2.14 fixed-point format, taking the `avar`
+    /// table into account.
+    ///
+    /// At most one coordinate per axis of the face is normalized.
+    ///
+    /// ```
+    /// # use harfbuzz::Face;
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansVariable-Roman.abc.ttf", 0).unwrap();
+    /// assert_eq!(face.normalize_coords(&[700.0]), vec![11821]);
+    /// ```
+    pub fn normalize_coords(&self, design_coords: &[f32]) -> Vec<i32> {
+        let length = design_coords.len().min(self.get_axis_count() as usize);
+        let mut coords = vec![0; length];
+        unsafe {
+            sys::hb_ot_var_normalize_coords(
+                self.raw,
+                length as c_uint,
+                design_coords.as_ptr(),
+                coords.as_mut_ptr(),
+            );
+        }
+        coords
+    }
+
+    /// Fetches the number of named instances included in the face.
+    pub fn get_named_instance_count(&self) -> u32 {
+        unsafe { sys::hb_ot_var_get_named_instance_count(self.raw) }
+    }
+
+    /// Fetches the named instance of the face at the given index, with
+    /// its names resolved from the `name` table.
+    ///
+    /// Returns `None` if `index` is out of range.
+    ///
+    /// ```
+    /// # use harfbuzz::Face;
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansVariable-Roman.abc.ttf", 0).unwrap();
+    /// let black = face.get_named_instance(5).unwrap();
+    /// assert_eq!(black.subfamily_name.as_deref(), Some("Black"));
+    /// assert_eq!(black.coords, vec![900.0, 0.0]);
+    /// assert!(face.get_named_instance(face.get_named_instance_count()).is_none());
+    /// ```
+    pub fn get_named_instance(&self, index: u32) -> Option<NamedInstance> {
+        if index >= self.get_named_instance_count() {
+            return None;
+        }
+        unsafe {
+            let subfamily_name_id = sys::hb_ot_var_named_instance_get_subfamily_name_id(self.raw, index).into();
+            let postscript_name_id =
+                match sys::hb_ot_var_named_instance_get_postscript_name_id(self.raw, index) {
+                    sys::HB_OT_NAME_ID_INVALID => None,
+                    name_id => Some(name_id.into()),
+                };
+            let mut length =
+                sys::hb_ot_var_named_instance_get_design_coords(self.raw, index, ptr::null_mut(), ptr::null_mut());
+            let mut coords = vec![0.0; length as usize];
+            sys::hb_ot_var_named_instance_get_design_coords(self.raw, index, &mut length, coords.as_mut_ptr());
+            coords.truncate(length as usize);
+            Some(NamedInstance {
+                index,
+                subfamily_name_id,
+                subfamily_name: self.get_name_utf8(subfamily_name_id, ptr::null()),
+                postscript_name_id,
+                postscript_name: postscript_name_id.and_then(|name_id| self.get_name_utf8(name_id, ptr::null())),
+                coords,
+            })
+        }
+    }
+
+    /// Fetches all the named instances of the face.
+    pub fn named_instances(&self) -> Vec<NamedInstance> {
+        (0..self.get_named_instance_count())
+            .filter_map(|index| self.get_named_instance(index))
+            .collect()
+    }
+
+    /// Returns true if the face has color palettes.
+    pub fn has_color_palettes(&self) -> bool {
+        unsafe { sys::hb_ot_color_has_palettes(self.raw) != 0 }
+    }
+
+    /// Fetches the number of color palettes of the face.
+    pub fn get_color_palette_count(&self) -> u32 {
+        unsafe { sys::hb_ot_color_palette_get_count(self.raw) }
+    }
+
+    /// Fetches a color palette of the face, if it exists.
+    ///
+    /// ```
+    /// # use harfbuzz::{Color, Face, NameId, PaletteFlags};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/cpal-v1.ttf", 0).unwrap();
+    /// assert!(face.has_color_palettes());
+    /// assert_eq!(face.get_color_palette_count(), 3);
+    ///
+    /// let palette = face.get_color_palette(0).unwrap();
+    /// assert_eq!(palette.flags, PaletteFlags::USABLE_WITH_DARK_BACKGROUND);
+    /// assert_eq!(palette.name_id, Some(NameId::Other(257)));
+    /// assert_eq!(palette.colors, vec![Color::new(0, 0, 0, 255), Color::new(0x66, 0xcc, 0xff, 255)]);
+    /// assert_eq!(face.get_palette_color_name_id(1), Some(NameId::Other(256)));
+    /// assert!(face.get_color_palette(3).is_none());
+    /// ```
+    pub fn get_color_palette(&self, index: u32) -> Option<ColorPalette> {
+        if index >= self.get_color_palette_count() {
+            return None;
+        }
+        unsafe {
+            let name_id = match sys::hb_ot_color_palette_get_name_id(self.raw, index) {
+                sys::HB_OT_NAME_ID_INVALID => None,
+                name_id => Some(name_id.into()),
+            };
+            let mut length =
+                sys::hb_ot_color_palette_get_colors(self.raw, index, 0, ptr::null_mut(), ptr::null_mut());
+            let mut colors = vec![0; length as usize];
+            sys::hb_ot_color_palette_get_colors(self.raw, index, 0, &mut length, colors.as_mut_ptr());
+            colors.truncate(length as usize);
+            Some(ColorPalette {
+                index,
+                flags: PaletteFlags::from_bits_retain(sys::hb_ot_color_palette_get_flags(self.raw, index)),
+                name_id,
+                colors: colors.into_iter().map(Color::from).collect(),
+            })
+        }
+    }
+
+    /// Fetches all the color palettes of the face.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, PaletteFlags};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/cpal-v1.ttf", 0).unwrap();
+    /// let light = face
+    ///     .color_palettes()
+    ///     .into_iter()
+    ///     .find(|palette| palette.flags.contains(PaletteFlags::USABLE_WITH_LIGHT_BACKGROUND))
+    ///     .unwrap();
+    /// assert_eq!(light.index, 1);
+    /// ```
+    pub fn color_palettes(&self) -> Vec<ColorPalette> {
+        (0..self.get_color_palette_count())
+            .filter_map(|index| self.get_color_palette(index))
+            .collect()
+    }
+
+    /// Fetches the `name` table ID of the name of a color, by its index in
+    /// the color palettes, if it has one.
+    pub fn get_palette_color_name_id(&self, color_index: u32) -> Option<NameId> {
+        match unsafe { sys::hb_ot_color_palette_color_get_name_id(self.raw, color_index) } {
+            sys::HB_OT_NAME_ID_INVALID => None,
+            name_id => Some(name_id.into()),
+        }
+    }
+
+    /// Returns true if the face has `COLR` version 0 color layers.
+    pub fn has_color_layers(&self) -> bool {
+        unsafe { sys::hb_ot_color_has_layers(self.raw) != 0 }
+    }
+
+    /// Fetches the layers of a color glyph, from bottom to top.
+    ///
+    /// The result is empty if the glyph has no layers.
+    ///
+    /// ```
+    /// # use harfbuzz::{ColorLayer, Face};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/TwemojiMozilla.subset.ttf", 0).unwrap();
+    /// assert!(face.has_color_layers());
+    /// let layers = face.color_layers(2);
+    /// assert_eq!(
+    ///     layers,
+    ///     vec![ColorLayer { glyph: 4, color_index: 0 }, ColorLayer { glyph: 5, color_index: 1 }]
+    /// );
+    /// assert_eq!(face.color_layers_iter(2).collect::<Vec<_>>(), layers);
+    /// assert_eq!(face.color_layers(0), vec![]);
+    /// ```
+    pub fn color_layers(&self, glyph: u32) -> Vec<ColorLayer> {
+        unsafe {
+            let mut length = sys::hb_ot_color_glyph_get_layers(self.raw, glyph, 0, ptr::null_mut(), ptr::null_mut());
+            let mut layers = Vec::with_capacity(length as usize);
+            sys::hb_ot_color_glyph_get_layers(self.raw, glyph, 0, &mut length, layers.as_mut_ptr());
+            layers.set_len(length as usize);
+            layers.into_iter().map(ColorLayer::from).collect()
+        }
+    }
+
+    /// Iterates over the layers of a color glyph, from bottom to top,
+    /// without fetching them all at once.
+    pub fn color_layers_iter(&self, glyph: u32) -> ColorLayers<'_> {
+        ColorLayers::new(self.raw, glyph)
+    }
+
+    /// Returns true if the face has color glyphs stored as SVG documents.
+    pub fn has_color_svg(&self) -> bool {
+        unsafe { sys::hb_ot_color_has_svg(self.raw) != 0 }
+    }
+
+    /// Fetches the SVG document of a color glyph, if it has one.
+    ///
+    /// A document may hold several glyphs, each being the element whose
+    /// `id` is `glyph` followed by the glyph ID.
+    ///
+    /// ```
+    /// # use harfbuzz::Face;
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/chromacheck-svg.ttf", 0).unwrap();
+    /// assert!(face.has_color_svg());
+    /// let svg = face.get_glyph_svg(1).unwrap();
+    /// assert!(String::from_utf8_lossy(&svg).contains("id=\"glyph1\""));
+    /// assert!(face.get_glyph_svg(0).is_none());
+    /// ```
+    pub fn get_glyph_svg(&self, glyph: u32) -> Option<Blob<'a>> {
+        let blob = unsafe { Blob::from_raw(sys::hb_ot_color_glyph_reference_svg(self.raw, glyph)) };
+        (!blob.is_empty()).then_some(blob)
+    }
+
+    /// Returns true if the face has color glyphs stored as PNG images.
+    pub fn has_color_png(&self) -> bool {
+        unsafe { sys::hb_ot_color_has_png(self.raw) != 0 }
+    }
+
+    /// Lists the strings of the face's `name` table, by ID and language.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Language, NameId};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let names = face.names();
+    /// assert_eq!(names.len(), 18);
+    /// assert!(names.iter().any(|entry| entry.name_id == NameId::FontFamily));
+    /// ```
+    pub fn names(&self) -> Vec<NameEntry> {
+        unsafe {
+            let mut length = 0;
+            let entries = sys::hb_ot_name_list_names(self.raw, &mut length);
+            if entries.is_null() {
+                return Vec::new();
+            }
+            slice::from_raw_parts(entries, length as usize)
+                .iter()
+                .map(|entry| NameEntry {
+                    name_id: entry.name_id.into(),
+                    language: Language::from_raw(entry.language),
+                })
+                .collect()
+        }
+    }
+
+    /// Fetches a string of the face's `name` table in the given language,
+    /// if present.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Language, NameId};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let en = Language::from_string("en");
+    /// assert_eq!(face.name(NameId::FontFamily, en).as_deref(), Some("Source Sans Pro"));
+    /// assert_eq!(face.name(NameId::PostScriptName, en).as_deref(), Some("SourceSansPro-Regular"));
+    /// assert_eq!(face.name(NameId::Other(1000), en), None);
+    /// ```
+    pub fn name(&self, name_id: NameId, language: Language) -> Option<String> {
+        self.get_name_utf8(name_id, language.as_raw())
+    }
+
+    /// Fetches a string from the `name` table, converted to UTF-8.
+    ///
+    /// A null `language` selects English.
+    fn get_name_utf8(&self, name_id: NameId, language: sys::hb_language_t) -> Option<String> {
+        let name_id = name_id.into();
+        unsafe {
+            let length = sys::hb_ot_name_get_utf8(self.raw, name_id, language, ptr::null_mut(), ptr::null_mut());
+            if length == 0 {
+                return None;
+            }
+            // The buffer has room for the terminating nul.
+            let mut size: c_uint = length + 1;
+            let mut buf = vec![0u8; size as usize];
+            sys::hb_ot_name_get_utf8(self.raw, name_id, language, &mut size, buf.as_mut_ptr() as *mut c_char);
+            buf.truncate(size as usize);
+            String::from_utf8(buf).ok()
+        }
+    }
+
+    /// Fetches the raw data of a table of the face, if present.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Tag};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let head = face.table(Tag::from_string("head")).unwrap();
+    /// assert_eq!(head.len(), 54);
+    /// assert!(face.table(Tag::from_string("glyf")).is_none());
+    /// ```
+    pub fn table(&self, tag: Tag) -> Option<Blob<'a>> {
+        let blob = unsafe { Blob::from_raw(sys::hb_face_reference_table(self.raw, tag.into())) };
+        (!blob.is_empty()).then_some(blob)
+    }
+
+    /// Fetches the blob the face was created from.
+    ///
+    /// For a face from a collection, this is the data of the whole
+    /// collection. The result is empty if the face was not created from a
+    /// blob.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Tag};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/shape/data/in-house/fonts/TTC.ttc", 1).unwrap();
+    /// assert_eq!(face.reference_blob().len(), 2608);
+    /// assert_eq!(face.table(Tag::from_string("head")).unwrap().len(), 54);
+    /// ```
+    pub fn reference_blob(&self) -> Blob<'a> {
+        unsafe { Blob::from_raw(sys::hb_face_reference_blob(self.raw)) }
+    }
+
+    /// Iterates over the tags of the tables of the face, in the order they
+    /// appear in the font's table directory.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Tag};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let tags: Vec<Tag> = face.table_tags().collect();
+    /// assert!(tags.contains(&Tag::from_string("CFF ")));
+    /// assert!(tags.contains(&Tag::from_string("OS/2")));
+    /// assert!(!tags.contains(&Tag::from_string("glyf")));
+    /// ```
+    pub fn table_tags(&self) -> TableTags<'_> {
+        TableTags {
+            face: self.raw,
+            pages: Pages::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Collects the Unicode characters covered by the face's `cmap` table.
+    ///
+    /// ```
+    /// # use harfbuzz::Face;
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/Roboto-Regular.abc.ttf", 0).unwrap();
+    /// assert_eq!(face.collect_unicodes().into_iter().collect::<Vec<_>>(), [0x61, 0x62, 0x63]);
+    /// ```
+    pub fn collect_unicodes(&self) -> BTreeSet<u32> {
+        collect_set(|set| unsafe { sys::hb_face_collect_unicodes(self.raw, set) })
+    }
+
+    /// Collects the mapping from the Unicode characters covered by the
+    /// face's `cmap` table to their nominal glyphs.
+    ///
+    /// ```
+    /// # use harfbuzz::Face;
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/Roboto-Regular.abc.ttf", 0).unwrap();
+    /// let mapping = face.collect_nominal_glyph_mapping();
+    /// assert_eq!(mapping.len(), 3);
+    /// assert!(mapping.contains_key(&0x61));
+    /// ```
+    pub fn collect_nominal_glyph_mapping(&self) -> HashMap<u32, u32> {
+        unsafe {
+            let map = sys::hb_map_create();
+            sys::hb_face_collect_nominal_glyph_mapping(self.raw, map, ptr::null_mut());
+            let mut mapping = HashMap::with_capacity(sys::hb_map_get_population(map) as usize);
+            let mut index = -1;
+            let (mut unicode, mut glyph) = (0, 0);
+            while sys::hb_map_next(map, &mut index, &mut unicode, &mut glyph) != 0 {
+                mapping.insert(unicode, glyph);
+            }
+            sys::hb_map_destroy(map);
+            mapping
+        }
+    }
+
+    /// Collects the variation selectors covered by the face's `cmap`
+    /// table.
+    ///
+    /// ```
+    /// # use harfbuzz::Face;
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/NotoColorEmoji.cmap.ttf", 0).unwrap();
+    /// assert_eq!(face.collect_variation_selectors().into_iter().collect::<Vec<_>>(), [0xFE0F]);
+    /// let unicodes = face.collect_variation_unicodes(0xFE0F);
+    /// assert_eq!(unicodes.into_iter().collect::<Vec<_>>(), [0x38, 0x39, 0xAE, 0x2049]);
+    /// assert!(face.collect_variation_unicodes(0xFE0E).is_empty());
+    /// ```
+    pub fn collect_variation_selectors(&self) -> BTreeSet<u32> {
+        collect_set(|set| unsafe { sys::hb_face_collect_variation_selectors(self.raw, set) })
+    }
+
+    /// Collects the Unicode characters for which the face's `cmap` table
+    /// has a variant with the given variation selector.
+    pub fn collect_variation_unicodes(&self, variation_selector: u32) -> BTreeSet<u32> {
+        collect_set(|set| unsafe { sys::hb_face_collect_variation_unicodes(self.raw, variation_selector, set) })
+    }
+
     /// Make this face immutable.
     pub fn make_immutable(&mut self) {
         unsafe {
@@ -126,3 +673,33 @@ impl<'a> Drop for Face<'a> {
         }
     }
 }
+
+/// Runs `collect` on a new set, and returns the contents of the set.
+fn collect_set(collect: impl FnOnce(*mut sys::hb_set_t)) -> BTreeSet<u32> {
+    let set = Set::new();
+    collect(set.as_ptr());
+    set.iter().collect()
+}
+
+/// An iterator over the table tags of a [`Face`].
+///
+/// See [`Face::table_tags`].
+pub struct TableTags<'a> {
+    face: *mut sys::hb_face_t,
+    pages: Pages<sys::hb_tag_t>,
+    phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> Iterator for TableTags<'a> {
+    type Item = Tag;
+
+    fn next(&mut self) -> Option<Tag> {
+        let face = self.face;
+        let tag = unsafe {
+            self.pages.next(|offset, count, tags| {
+                sys::hb_face_get_table_tags(face, offset, count, tags);
+            })
+        };
+        tag.map(Tag::from)
+    }
+}
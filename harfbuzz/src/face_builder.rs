@@ -0,0 +1,99 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::marker::PhantomData;
+use std::mem;
+use sys;
+
+use crate::{Blob, Face, Tag};
+
+/// Assembles a font face from individual tables.
+///
+/// The resulting face can be used like any other, and serialized to a
+/// font file in the sfnt format:
+///
+/// ```
+/// # use harfbuzz::{Face, FaceBuilder, Tag};
+/// let source = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+/// let mut builder = FaceBuilder::new();
+/// for tag in source.table_tags() {
+///     builder.add_table(tag, source.table(tag).unwrap()).unwrap();
+/// }
+/// builder.sort_tables(&[Tag::from_string("name"), Tag::from_string("head")]);
+///
+/// let face = Face::new(builder.to_blob(), 0);
+/// assert_eq!(face.get_glyph_count(), 1942);
+/// assert!(face.table_tags().eq(source.table_tags()));
+///
+/// let face = builder.into_face();
+/// assert_eq!(face.get_upem(), 1000);
+/// ```
+pub struct FaceBuilder<'a> {
+    raw: *mut sys::hb_face_t,
+    phantom: PhantomData<&'a [u8]>,
+}
+
+impl<'a> FaceBuilder<'a> {
+    /// Create a new builder, with no tables.
+    pub fn new() -> Self {
+        FaceBuilder {
+            raw: unsafe { sys::hb_face_builder_create() },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Adds a table to the face, replacing any table previously added with
+    /// the same tag.
+    pub fn add_table(&mut self, tag: Tag, blob: Blob<'a>) -> Result<(), super::ErrorKind> {
+        if unsafe { sys::hb_face_builder_add_table(self.raw, tag.into(), blob.as_ptr()) } != 0 {
+            Ok(())
+        } else {
+            Err(super::ErrorKind::HbFailure)
+        }
+    }
+
+    /// Sets the order of the table data in the serialized font.
+    ///
+    /// The tables in `tags` come first, in that order, and the others
+    /// follow. The table directory itself is always sorted by tag.
+    pub fn sort_tables(&mut self, tags: &[Tag]) {
+        let mut tags: Vec<sys::hb_tag_t> = tags.iter().map(|&tag| tag.into()).collect();
+        // The list is terminated by `HB_TAG_NONE`.
+        tags.push(0);
+        unsafe { sys::hb_face_builder_sort_tables(self.raw, tags.as_ptr()) }
+    }
+
+    /// Serializes the face to a font file in the sfnt format.
+    pub fn to_blob(&self) -> Blob<'a> {
+        unsafe { Blob::from_raw(sys::hb_face_reference_blob(self.raw)) }
+    }
+
+    /// Turns the builder into the face it has assembled.
+    pub fn into_face(self) -> Face<'a> {
+        let raw = self.raw;
+        mem::forget(self);
+        unsafe { Face::from_raw(raw) }
+    }
+}
+
+impl<'a> Default for FaceBuilder<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Drop for FaceBuilder<'a> {
+    /// Decrement the reference count, and destroy the face if the
+    /// reference count is zero.
+    fn drop(&mut self) {
+        unsafe {
+            sys::hb_face_destroy(self.raw);
+        }
+    }
+}
@@ -9,8 +9,11 @@
 
 #![allow(missing_docs)]
 
+use std::ops::{Bound, RangeBounds};
 use sys;
 
+use crate::Tag;
+
 /// This structure holds information about requested feature application.
 #[repr(C)]
 pub struct Feature {
@@ -34,6 +37,47 @@ impl Feature {
         Self { hb_feature: sys::hb_feature_t { tag, value: 0, start, end }}
     }
 
+    /// Creates a feature with the given value, applied to the clusters
+    /// within `range`.
+    ///
+    /// Unbounded ends map to the global start and end of the buffer.
+    ///
+    /// ```
+    /// # use harfbuzz::{Feature, Tag};
+    /// let smcp = Tag::from_string("smcp");
+    /// let feature = Feature::new(smcp, 1, 6..11);
+    /// assert_eq!((feature.start(), feature.end()), (6, 11));
+    ///
+    /// let feature = Feature::new(smcp, 1, 6..);
+    /// assert_eq!((feature.start(), feature.end()), (6, u32::MAX));
+    /// ```
+    pub fn new(tag: Tag, value: u32, range: impl RangeBounds<u32>) -> Feature {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => u32::MAX,
+        };
+        Self { hb_feature: sys::hb_feature_t { tag: tag.into(), value, start, end }}
+    }
+
+    /// Creates a feature enabled for the clusters within `range`.
+    ///
+    /// Use [`Buffer::cluster_range`](crate::Buffer::cluster_range) to
+    /// obtain `range` from a byte range of the text in the buffer.
+    pub fn enable_for_range(tag: Tag, range: impl RangeBounds<u32>) -> Feature {
+        Feature::new(tag, 1, range)
+    }
+
+    /// Creates a feature disabled for the clusters within `range`.
+    pub fn disable_for_range(tag: Tag, range: impl RangeBounds<u32>) -> Feature {
+        Feature::new(tag, 0, range)
+    }
+
     pub fn tag(&self) -> u32 {
         self.hb_feature.tag
     }
@@ -7,11 +7,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::Face;
+use super::{
+    Blob, Color, Direction, DrawSink, Face, FontFuncs, MetricsTag, PaintSink, PngGlyph, Strided, StridedMut, Tag,
+    Variation,
+};
+use crate::{draw, font_funcs, paint};
 use std::marker::PhantomData;
-use std::mem;
+use std::mem::MaybeUninit;
+use std::os::raw::c_uint;
+use std::{mem, slice};
 use sys;
 
+/// `HB_FONT_NO_VAR_NAMED_INSTANCE` is a macro, so it is missing from the
+/// generated bindings.
+const HB_FONT_NO_VAR_NAMED_INSTANCE: c_uint = 0xFFFFFFFF;
+
 /// A font object represents a font face at a specific size and with
 /// certain other parameters (pixels-per-em, points-per-em, variation
 /// settings) specified. `Font` objects are created from font `Face`
@@ -38,6 +48,62 @@ impl<'a> Font<'a> {
         }
     }
 
+    /// Constructs a sub-font of `parent`.
+    ///
+    /// The sub-font starts with the same face, scale and variation
+    /// settings as its parent, and delegates all queries to it. Use
+    /// [`set_funcs`] to override selected queries: the methods of
+    /// [`FontFuncs`] that are not overridden keep delegating to the
+    /// parent, scaled to the sub-font's scale.
+    ///
+    /// ```
+    /// # use harfbuzz::{hb_shape, Buffer, Face, Font, FontFuncs};
+    /// /// Narrows the advance of one glyph, keeping the parent's metrics
+    /// /// for all others.
+    /// struct Narrow(u32);
+    ///
+    /// impl FontFuncs for Narrow {
+    ///     fn glyph_h_advance(&self, font: &Font, glyph: u32) -> i32 {
+    ///         let advance = font.get_parent().unwrap().get_glyph_h_advance(glyph);
+    ///         if glyph == self.0 { advance / 2 } else { advance }
+    ///     }
+    /// }
+    ///
+    /// fn shape(font: &Font) -> (Vec<u32>, Vec<i32>) {
+    ///     let mut buffer = Buffer::with("abc");
+    ///     buffer.guess_segment_properties();
+    ///     let shaped = hb_shape(font, buffer, &[]);
+    ///     (shaped.get_glyph_infos().iter().map(|info| info.index()).collect(),
+    ///      shaped.get_glyph_positions().iter().map(|pos| pos.x_advance()).collect())
+    /// }
+    ///
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let font = Font::new(&face);
+    /// let (glyphs, expected) = shape(&font);
+    ///
+    /// let mut narrow = Font::new_sub_font(&font);
+    /// narrow.set_funcs(Narrow(glyphs[1]));
+    /// assert_eq!(shape(&narrow), (glyphs.clone(), vec![expected[0], expected[1] / 2, expected[2]]));
+    ///
+    /// // Queries that are not overridden follow the scale of the sub-font.
+    /// struct Delegate;
+    /// impl FontFuncs for Delegate {}
+    ///
+    /// let mut large = Font::new_sub_font(&font);
+    /// large.set_funcs(Delegate);
+    /// large.set_scale(2000, 2000);
+    /// assert_eq!(shape(&large).1, expected.iter().map(|advance| advance * 2).collect::<Vec<_>>());
+    /// ```
+    ///
+    /// [`set_funcs`]: #method.set_funcs
+    /// [`FontFuncs`]: crate::FontFuncs
+    pub fn new_sub_font(parent: &Font) -> Font<'static> {
+        unsafe {
+            let font = sys::hb_font_create_sub_font(parent.as_ptr());
+            Font::from_raw(font)
+        }
+    }
+
     /// Construct a `Font` from a raw pointer. Takes ownership of the font.
     pub unsafe fn from_raw(raw: *mut sys::hb_font_t) -> Self {
         Font {
@@ -46,6 +112,32 @@ impl<'a> Font<'a> {
         }
     }
 
+    /// Sets the parent font of the font.
+    pub fn set_parent(&mut self, parent: &Font) {
+        unsafe {
+            sys::hb_font_set_parent(self.raw, parent.as_ptr());
+        }
+    }
+
+    /// Fetches the parent font of the font, or `None` if the font has
+    /// no parent.
+    ///
+    /// Fonts created with [`new`] have no parent, those created with
+    /// [`new_sub_font`] have the font they were created from.
+    ///
+    /// [`new`]: #method.new
+    /// [`new_sub_font`]: #method.new_sub_font
+    pub fn get_parent(&self) -> Option<Font<'static>> {
+        unsafe {
+            let parent = sys::hb_font_get_parent(self.raw);
+            if parent.is_null() || parent == sys::hb_font_get_empty() {
+                None
+            } else {
+                Some(Font::from_raw(sys::hb_font_reference(parent)))
+            }
+        }
+    }
+
     /// Sets the horizontal and vertical pixels-per-em (PPEM) of a font.
     pub fn set_ppem(&mut self, x_ppem: u32, y_ppem: u32) {
         unsafe {
@@ -98,6 +190,619 @@ impl<'a> Font<'a> {
         }
     }
 
+    /// Sets synthetic emboldening of a font, in em units.
+    ///
+    /// Positive values make glyphs bolder, and negative values lighter;
+    /// 0.02 is a reasonable value for a bold face. Unless `in_place` is
+    /// true, glyph advances grow with the emboldening, and shaping takes
+    /// it into account.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let mut font = Font::new(&face);
+    /// let a = font.get_nominal_glyph('a').unwrap();
+    /// assert_eq!(font.get_glyph_h_advance(a), 504);
+    ///
+    /// font.set_synthetic_bold(0.02, 0.02, false);
+    /// assert_eq!(font.get_synthetic_bold(), (0.02, 0.02, false));
+    /// assert_eq!(font.get_glyph_h_advance(a), 524);
+    ///
+    /// font.set_synthetic_bold(0.02, 0.02, true);
+    /// assert_eq!(font.get_glyph_h_advance(a), 504);
+    /// ```
+    pub fn set_synthetic_bold(&mut self, x_embolden: f32, y_embolden: f32, in_place: bool) {
+        unsafe {
+            sys::hb_font_set_synthetic_bold(self.raw, x_embolden, y_embolden, in_place as sys::hb_bool_t);
+        }
+    }
+
+    /// Fetches the synthetic emboldening of a font, as the x and y
+    /// emboldening in em units and whether it is applied in place.
+    pub fn get_synthetic_bold(&self) -> (f32, f32, bool) {
+        let (mut x_embolden, mut y_embolden, mut in_place) = (0.0, 0.0, 0);
+        unsafe {
+            sys::hb_font_get_synthetic_bold(self.raw, &mut x_embolden, &mut y_embolden, &mut in_place);
+        }
+        (x_embolden, y_embolden, in_place != 0)
+    }
+
+    /// Sets the synthetic slant of a font, as the horizontal shift per
+    /// unit of height.
+    ///
+    /// Positive values slant glyphs to the right; 0.2 is a reasonable
+    /// value for an oblique face. The slant applies to drawn outlines and
+    /// glyph extents, and to the positions of marks when shaping.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let mut font = Font::new(&face);
+    /// let l = font.get_nominal_glyph('l').unwrap();
+    /// let upright = font.get_glyph_extents(l).unwrap();
+    ///
+    /// font.set_synthetic_slant(0.2);
+    /// assert_eq!(font.get_synthetic_slant(), 0.2);
+    /// let slanted = font.get_glyph_extents(l).unwrap();
+    /// assert!(slanted.width > upright.width);
+    /// ```
+    pub fn set_synthetic_slant(&mut self, slant: f32) {
+        unsafe {
+            sys::hb_font_set_synthetic_slant(self.raw, slant);
+        }
+    }
+
+    /// Fetches the synthetic slant of a font.
+    pub fn get_synthetic_slant(&self) -> f32 {
+        unsafe { sys::hb_font_get_synthetic_slant(self.raw) }
+    }
+
+    /// Fetches the extents of the font for horizontal text, if the font
+    /// provides them.
+    ///
+    /// ```
+    /// # use harfbuzz::{Direction, Face, Font, FontExtents};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let font = Font::new(&face);
+    /// let extents = font.get_h_extents().unwrap();
+    /// assert_eq!(extents, FontExtents { ascender: 984, descender: -273, line_gap: 0 });
+    /// assert_eq!(font.get_extents_for_direction(Direction::LTR), extents);
+    /// ```
+    pub fn get_h_extents(&self) -> Option<FontExtents> {
+        let mut extents = MaybeUninit::uninit();
+        unsafe {
+            if sys::hb_font_get_h_extents(self.raw, extents.as_mut_ptr()) != 0 {
+                Some(extents.assume_init().into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Fetches the extents of the font for vertical text, if the font
+    /// provides them.
+    pub fn get_v_extents(&self) -> Option<FontExtents> {
+        let mut extents = MaybeUninit::uninit();
+        unsafe {
+            if sys::hb_font_get_v_extents(self.raw, extents.as_mut_ptr()) != 0 {
+                Some(extents.assume_init().into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Fetches the extents of the font for text in the given direction,
+    /// synthesizing them if the font lacks them.
+    pub fn get_extents_for_direction(&self, direction: Direction) -> FontExtents {
+        let mut extents = MaybeUninit::uninit();
+        unsafe {
+            sys::hb_font_get_extents_for_direction(self.raw, direction.into(), extents.as_mut_ptr());
+            extents.assume_init().into()
+        }
+    }
+
+    /// Fetches a font-wide metric, if the font provides it.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font, MetricsTag};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let font = Font::new(&face);
+    /// assert_eq!(font.get_metrics_position(MetricsTag::XHeight), Some(486));
+    /// assert_eq!(font.get_metrics_position(MetricsTag::UnderlineOffset), Some(-50));
+    /// assert_eq!(font.get_metrics_position(MetricsTag::VerticalCaretRise), None);
+    /// ```
+    pub fn get_metrics_position(&self, tag: MetricsTag) -> Option<i32> {
+        let mut position = 0;
+        unsafe { sys::hb_ot_metrics_get_position(self.raw, tag.into(), &mut position) != 0 }.then_some(position)
+    }
+
+    /// Fetches a font-wide metric, synthesizing a value from other
+    /// metrics if the font lacks it.
+    pub fn get_metrics_position_with_fallback(&self, tag: MetricsTag) -> i32 {
+        let mut position = 0;
+        unsafe {
+            sys::hb_ot_metrics_get_position_with_fallback(self.raw, tag.into(), &mut position);
+        }
+        position
+    }
+
+    /// Fetches the variation of a font-wide metric at the font's
+    /// variation coordinates, in font units.
+    ///
+    /// This is zero unless the font has an `MVAR` table.
+    pub fn get_metrics_variation(&self, tag: MetricsTag) -> f32 {
+        unsafe { sys::hb_ot_metrics_get_variation(self.raw, tag.into()) }
+    }
+
+    /// Fetches the variation of a horizontal font-wide metric at the
+    /// font's variation coordinates, scaled by the font's x scale.
+    pub fn get_metrics_x_variation(&self, tag: MetricsTag) -> i32 {
+        unsafe { sys::hb_ot_metrics_get_x_variation(self.raw, tag.into()) }
+    }
+
+    /// Fetches the variation of a vertical font-wide metric at the
+    /// font's variation coordinates, scaled by the font's y scale.
+    pub fn get_metrics_y_variation(&self, tag: MetricsTag) -> i32 {
+        unsafe { sys::hb_ot_metrics_get_y_variation(self.raw, tag.into()) }
+    }
+
+    /// Fetches the nominal glyph ID for a character, if the font has one.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let font = Font::new(&face);
+    /// assert_eq!(font.get_nominal_glyph('a'), Some(28));
+    /// assert_eq!(font.get_nominal_glyph('\u{10FFFF}'), None);
+    /// ```
+    pub fn get_nominal_glyph(&self, c: char) -> Option<u32> {
+        let mut glyph = 0;
+        unsafe { sys::hb_font_get_nominal_glyph(self.raw, c as u32, &mut glyph) != 0 }.then_some(glyph)
+    }
+
+    /// Fetches the nominal glyph IDs for a sequence of characters.
+    ///
+    /// Mapping stops at the first character the font has no glyph for;
+    /// the number of characters mapped is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars` and `glyphs` have different lengths.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let font = Font::new(&face);
+    /// let mut glyphs = [0; 4];
+    /// assert_eq!(font.get_nominal_glyphs(&['a', 'b', '\u{10FFFF}', 'c'], &mut glyphs), 2);
+    /// assert_eq!(glyphs[..2], [28, 29]);
+    /// ```
+    pub fn get_nominal_glyphs(&self, chars: &[char], glyphs: &mut [u32]) -> usize {
+        self.get_nominal_glyphs_strided(chars.into(), glyphs.into())
+    }
+
+    /// Fetches the nominal glyph IDs for a sequence of characters stored
+    /// in a field of each element of an array, writing them into a field
+    /// of each element of another array.
+    ///
+    /// Mapping stops at the first character the font has no glyph for;
+    /// the number of characters mapped is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chars` and `glyphs` have different lengths.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font, Strided, StridedMut};
+    /// #[derive(Clone, Copy, Default)]
+    /// struct Cell {
+    ///     glyph: u32,
+    ///     advance: i32,
+    /// }
+    ///
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let font = Font::new(&face);
+    /// let text: Vec<char> = "abc".chars().collect();
+    /// let mut cells = [Cell::default(); 3];
+    /// let mapped = font.get_nominal_glyphs_strided(
+    ///     Strided::from(&text[..]),
+    ///     StridedMut::new(&mut cells, |cell| &mut cell.glyph),
+    /// );
+    /// assert_eq!(mapped, 3);
+    ///
+    /// let glyphs: Vec<u32> = cells.iter().map(|cell| cell.glyph).collect();
+    /// font.get_glyph_h_advances_strided(
+    ///     Strided::from(&glyphs[..]),
+    ///     StridedMut::new(&mut cells, |cell| &mut cell.advance),
+    /// );
+    /// assert_eq!(cells[0].advance, font.get_glyph_h_advance(cells[0].glyph));
+    /// ```
+    pub fn get_nominal_glyphs_strided(&self, chars: Strided<char>, mut glyphs: StridedMut<u32>) -> usize {
+        assert_eq!(chars.len(), glyphs.len());
+        unsafe {
+            sys::hb_font_get_nominal_glyphs(
+                self.raw,
+                chars.len() as c_uint,
+                chars.as_ptr() as *const sys::hb_codepoint_t,
+                chars.stride() as c_uint,
+                glyphs.as_mut_ptr(),
+                glyphs.stride() as c_uint,
+            ) as usize
+        }
+    }
+
+    /// Fetches the glyph ID for a character followed by a variation
+    /// selector, if the font has one.
+    pub fn get_variation_glyph(&self, c: char, variation_selector: char) -> Option<u32> {
+        let mut glyph = 0;
+        unsafe {
+            sys::hb_font_get_variation_glyph(self.raw, c as u32, variation_selector as u32, &mut glyph) != 0
+        }
+        .then_some(glyph)
+    }
+
+    /// Fetches the advance of a glyph for horizontal text.
+    ///
+    /// ```
+    /// # use harfbuzz::{Direction, Face, Font};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let font = Font::new(&face);
+    /// let a = font.get_nominal_glyph('a').unwrap();
+    /// assert_eq!(font.get_glyph_h_advance(a), 504);
+    /// assert_eq!(font.get_glyph_advance_for_direction(a, Direction::LTR), (504, 0));
+    /// assert_eq!(font.get_glyph_advance_for_direction(a, Direction::TTB), (0, -1257));
+    /// ```
+    pub fn get_glyph_h_advance(&self, glyph: u32) -> i32 {
+        unsafe { sys::hb_font_get_glyph_h_advance(self.raw, glyph) }
+    }
+
+    /// Fetches the advance of a glyph for vertical text.
+    pub fn get_glyph_v_advance(&self, glyph: u32) -> i32 {
+        unsafe { sys::hb_font_get_glyph_v_advance(self.raw, glyph) }
+    }
+
+    /// Fetches the advances of a sequence of glyphs for horizontal text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyphs` and `advances` have different lengths.
+    pub fn get_glyph_h_advances(&self, glyphs: &[u32], advances: &mut [i32]) {
+        self.get_glyph_h_advances_strided(glyphs.into(), advances.into());
+    }
+
+    /// Fetches the advances of a sequence of glyphs for horizontal text,
+    /// reading and writing them through strided views.
+    ///
+    /// See [`get_nominal_glyphs_strided`](#method.get_nominal_glyphs_strided).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyphs` and `advances` have different lengths.
+    pub fn get_glyph_h_advances_strided(&self, glyphs: Strided<u32>, mut advances: StridedMut<i32>) {
+        assert_eq!(glyphs.len(), advances.len());
+        unsafe {
+            sys::hb_font_get_glyph_h_advances(
+                self.raw,
+                glyphs.len() as c_uint,
+                glyphs.as_ptr(),
+                glyphs.stride() as c_uint,
+                advances.as_mut_ptr(),
+                advances.stride() as c_uint,
+            );
+        }
+    }
+
+    /// Fetches the advances of a sequence of glyphs for vertical text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyphs` and `advances` have different lengths.
+    pub fn get_glyph_v_advances(&self, glyphs: &[u32], advances: &mut [i32]) {
+        self.get_glyph_v_advances_strided(glyphs.into(), advances.into());
+    }
+
+    /// Fetches the advances of a sequence of glyphs for vertical text,
+    /// reading and writing them through strided views.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyphs` and `advances` have different lengths.
+    pub fn get_glyph_v_advances_strided(&self, glyphs: Strided<u32>, mut advances: StridedMut<i32>) {
+        assert_eq!(glyphs.len(), advances.len());
+        unsafe {
+            sys::hb_font_get_glyph_v_advances(
+                self.raw,
+                glyphs.len() as c_uint,
+                glyphs.as_ptr(),
+                glyphs.stride() as c_uint,
+                advances.as_mut_ptr(),
+                advances.stride() as c_uint,
+            );
+        }
+    }
+
+    /// Fetches the (x, y) advance of a glyph for text in the given
+    /// direction.
+    pub fn get_glyph_advance_for_direction(&self, glyph: u32, direction: Direction) -> (i32, i32) {
+        let (mut x, mut y) = (0, 0);
+        unsafe {
+            sys::hb_font_get_glyph_advance_for_direction(self.raw, glyph, direction.into(), &mut x, &mut y);
+        }
+        (x, y)
+    }
+
+    /// Fetches the (x, y) origin of a glyph for horizontal text, if the
+    /// font provides one.
+    pub fn get_glyph_h_origin(&self, glyph: u32) -> Option<(i32, i32)> {
+        let (mut x, mut y) = (0, 0);
+        unsafe { sys::hb_font_get_glyph_h_origin(self.raw, glyph, &mut x, &mut y) != 0 }.then_some((x, y))
+    }
+
+    /// Fetches the (x, y) origin of a glyph for vertical text, if the
+    /// font provides one.
+    pub fn get_glyph_v_origin(&self, glyph: u32) -> Option<(i32, i32)> {
+        let (mut x, mut y) = (0, 0);
+        unsafe { sys::hb_font_get_glyph_v_origin(self.raw, glyph, &mut x, &mut y) != 0 }.then_some((x, y))
+    }
+
+    /// Fetches the (x, y) origin of a glyph for text in the given
+    /// direction, synthesizing a vertical origin if the font lacks one.
+    pub fn get_glyph_origin_for_direction(&self, glyph: u32, direction: Direction) -> (i32, i32) {
+        let (mut x, mut y) = (0, 0);
+        unsafe {
+            sys::hb_font_get_glyph_origin_for_direction(self.raw, glyph, direction.into(), &mut x, &mut y);
+        }
+        (x, y)
+    }
+
+    /// Fetches the extents of a glyph, relative to its horizontal origin.
+    ///
+    /// ```
+    /// # use harfbuzz::{Direction, Face, Font, GlyphExtents};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+    /// let font = Font::new(&face);
+    /// let a = font.get_nominal_glyph('a').unwrap();
+    /// let extents = font.get_glyph_extents(a).unwrap();
+    /// assert_eq!(extents, GlyphExtents { x_bearing: 52, y_bearing: 498, width: 381, height: -510 });
+    /// assert_eq!(font.get_glyph_extents_for_origin(a, Direction::LTR), Some(extents));
+    /// ```
+    pub fn get_glyph_extents(&self, glyph: u32) -> Option<GlyphExtents> {
+        let mut extents = MaybeUninit::uninit();
+        unsafe {
+            if sys::hb_font_get_glyph_extents(self.raw, glyph, extents.as_mut_ptr()) != 0 {
+                Some(extents.assume_init().into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Fetches the extents of a glyph, relative to its origin for text
+    /// in the given direction.
+    pub fn get_glyph_extents_for_origin(&self, glyph: u32, direction: Direction) -> Option<GlyphExtents> {
+        let mut extents = MaybeUninit::uninit();
+        unsafe {
+            if sys::hb_font_get_glyph_extents_for_origin(self.raw, glyph, direction.into(), extents.as_mut_ptr()) != 0 {
+                Some(extents.assume_init().into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Fetches the (x, y) position of a point of a glyph's contours,
+    /// relative to its horizontal origin.
+    ///
+    /// Only fonts with TrueType outlines provide contour points.
+    pub fn get_glyph_contour_point(&self, glyph: u32, point_index: u32) -> Option<(i32, i32)> {
+        let (mut x, mut y) = (0, 0);
+        unsafe { sys::hb_font_get_glyph_contour_point(self.raw, glyph, point_index, &mut x, &mut y) != 0 }
+            .then_some((x, y))
+    }
+
+    /// Fetches the (x, y) position of a point of a glyph's contours,
+    /// relative to its origin for text in the given direction.
+    pub fn get_glyph_contour_point_for_origin(
+        &self,
+        glyph: u32,
+        point_index: u32,
+        direction: Direction,
+    ) -> Option<(i32, i32)> {
+        let (mut x, mut y) = (0, 0);
+        unsafe {
+            sys::hb_font_get_glyph_contour_point_for_origin(
+                self.raw,
+                glyph,
+                point_index,
+                direction.into(),
+                &mut x,
+                &mut y,
+            ) != 0
+        }
+        .then_some((x, y))
+    }
+
+    /// Draws the outline of a glyph into `sink`, relative to its
+    /// horizontal origin.
+    ///
+    /// The outline reflects the font's scale, variation coordinates and
+    /// synthetic slant and emboldening. See [`OutlinePath`] for a sink
+    /// collecting the outline.
+    ///
+    /// [`OutlinePath`]: crate::OutlinePath
+    pub fn draw_glyph(&self, glyph: u32, sink: &mut impl DrawSink) {
+        draw::draw_glyph(self.raw, glyph, sink);
+    }
+
+    /// Paints a color glyph into `sink`, with the colors of the palette
+    /// at `palette_index` and the given foreground color.
+    ///
+    /// Glyphs without color layers are painted as their outline filled
+    /// with the foreground color.
+    pub fn paint_glyph(&self, glyph: u32, sink: &mut impl PaintSink, palette_index: u32, foreground: Color) {
+        paint::paint_glyph(self.raw, glyph, sink, palette_index, foreground);
+    }
+
+    /// Fetches the PNG image of a color glyph and its extents, if it has
+    /// one.
+    ///
+    /// The image is taken from the strike best matching the font's ppem.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font, GlyphExtents};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/chromacheck-cbdt.ttf", 0).unwrap();
+    /// assert!(face.has_color_png());
+    /// let font = Font::new(&face);
+    /// let png = font.get_glyph_png(1).unwrap();
+    /// assert!(png.data.starts_with(b"\x89PNG"));
+    /// assert_eq!(png.extents, Some(GlyphExtents { x_bearing: 0, y_bearing: 1024, width: 1024, height: -1024 }));
+    /// assert!(font.get_glyph_png(0).is_none());
+    /// ```
+    pub fn get_glyph_png(&self, glyph: u32) -> Option<PngGlyph<'a>> {
+        let data = unsafe { Blob::from_raw(sys::hb_ot_color_glyph_reference_png(self.raw, glyph)) };
+        (!data.is_empty()).then(|| PngGlyph {
+            data,
+            extents: self.get_glyph_extents(glyph),
+        })
+    }
+
+    /// Applies a list of font-variation settings to a font.
+    ///
+    /// Axes not included in `variations` are set to their default
+    /// values, and settings for axes not present in the font are
+    /// ignored.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font, Variation};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansVariable-Roman.abc.ttf", 0).unwrap();
+    /// let mut font = Font::new(&face);
+    /// font.set_variations(&["wght=700".parse::<Variation>().unwrap()]);
+    /// assert_eq!(font.get_var_coords_design(), &[700.0, 0.0]);
+    /// assert_eq!(font.get_var_coords_normalized(), &[11821, 0]);
+    /// ```
+    pub fn set_variations(&mut self, variations: &[Variation]) {
+        unsafe {
+            sys::hb_font_set_variations(
+                self.raw,
+                variations.as_ptr() as *const sys::hb_variation_t,
+                variations.len() as c_uint,
+            );
+        }
+    }
+
+    /// Changes the value of one variation axis on the font, keeping the
+    /// values of the other axes.
+    pub fn set_variation(&mut self, tag: Tag, value: f32) {
+        unsafe {
+            sys::hb_font_set_variation(self.raw, tag.into(), value);
+        }
+    }
+
+    /// Applies a list of variation coordinates, in design-space units,
+    /// to a font.
+    ///
+    /// The coordinates are given in the order of the axes in the font's
+    /// `fvar` table.
+    pub fn set_var_coords_design(&mut self, coords: &[f32]) {
+        unsafe {
+            sys::hb_font_set_var_coords_design(self.raw, coords.as_ptr(), coords.len() as c_uint);
+        }
+    }
+
+    /// Fetches the list of variation coordinates, in design-space units,
+    /// currently set on the font.
+    ///
+    /// The design coordinates are derived from whichever coordinates were
+    /// set, including normalized ones. The list is empty if no
+    /// coordinates were set.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansVariable-Roman.abc.ttf", 0).unwrap();
+    /// let mut font = Font::new(&face);
+    /// assert!(font.get_var_coords_design().is_empty());
+    /// font.set_var_coords_normalized(&[8192, 0]);
+    /// let coords = font.get_var_coords_design();
+    /// assert!((coords[0] - 637.5).abs() < 0.01);
+    /// assert_eq!(coords[1], 0.0);
+    /// ```
+    pub fn get_var_coords_design(&self) -> &[f32] {
+        unsafe {
+            let mut length: c_uint = 0;
+            let coords = sys::hb_font_get_var_coords_design(self.raw, &mut length);
+            if coords.is_null() {
+                &[]
+            } else {
+                slice::from_raw_parts(coords, length as usize)
+            }
+        }
+    }
+
+    /// Applies a list of normalized variation coordinates to a font.
+    ///
+    /// Normalized coordinates are in 2.14 fixed-point format, ranging
+    /// from -16384 to 16384, in the order of the axes in the font's
+    /// `fvar` table.
+    pub fn set_var_coords_normalized(&mut self, coords: &[i32]) {
+        unsafe {
+            sys::hb_font_set_var_coords_normalized(self.raw, coords.as_ptr(), coords.len() as c_uint);
+        }
+    }
+
+    /// Fetches the list of normalized variation coordinates currently
+    /// set on the font, in 2.14 fixed-point format.
+    pub fn get_var_coords_normalized(&self) -> &[i32] {
+        unsafe {
+            let mut length: c_uint = 0;
+            let coords = sys::hb_font_get_var_coords_normalized(self.raw, &mut length);
+            if coords.is_null() {
+                &[]
+            } else {
+                slice::from_raw_parts(coords, length as usize)
+            }
+        }
+    }
+
+    /// Sets the design coordinates of the font to those of the named
+    /// instance at `index`.
+    ///
+    /// See [`Face::get_named_instance`](crate::Face::get_named_instance).
+    pub fn set_var_named_instance(&mut self, index: u32) {
+        unsafe {
+            sys::hb_font_set_var_named_instance(self.raw, index);
+        }
+    }
+
+    /// Fetches the index of the named instance set on the font with
+    /// [`set_var_named_instance`], or `None` if no named instance is set.
+    ///
+    /// ```
+    /// # use harfbuzz::{Face, Font};
+    /// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansVariable-Roman.abc.ttf", 0).unwrap();
+    /// let mut font = Font::new(&face);
+    /// assert_eq!(font.get_var_named_instance(), None);
+    /// font.set_var_named_instance(5);
+    /// assert_eq!(font.get_var_named_instance(), Some(5));
+    /// assert_eq!(font.get_var_coords_design(), &[900.0, 0.0]);
+    /// ```
+    ///
+    /// [`set_var_named_instance`]: #method.set_var_named_instance
+    pub fn get_var_named_instance(&self) -> Option<u32> {
+        match unsafe { sys::hb_font_get_var_named_instance(self.raw) } {
+            HB_FONT_NO_VAR_NAMED_INSTANCE => None,
+            index => Some(index),
+        }
+    }
+
+    /// Replaces the font functions of the font with `funcs`.
+    ///
+    /// HarfBuzz will query `funcs` for glyphs and metrics instead of the
+    /// tables of the face. See [`FontFuncs`](crate::FontFuncs).
+    pub fn set_funcs<F: FontFuncs + 'static>(&mut self, funcs: F) {
+        font_funcs::set_font_funcs(self.raw, funcs);
+    }
+
     /// Make this font immutable.
     pub fn make_immutable(&mut self) {
         unsafe {
@@ -131,3 +836,86 @@ impl<'a> Drop for Font<'a> {
         }
     }
 }
+
+/// The font-wide extents of a font, in font units scaled by the font's
+/// scale.
+///
+/// For horizontal text, `ascender` is usually positive and `descender`
+/// negative, as the y axis points up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FontExtents {
+    /// The height of typographic ascenders.
+    pub ascender: i32,
+    /// The depth of typographic descenders.
+    pub descender: i32,
+    /// The suggested gap between lines.
+    pub line_gap: i32,
+}
+
+impl From<sys::hb_font_extents_t> for FontExtents {
+    fn from(extents: sys::hb_font_extents_t) -> Self {
+        FontExtents {
+            ascender: extents.ascender,
+            descender: extents.descender,
+            line_gap: extents.line_gap,
+        }
+    }
+}
+
+impl From<FontExtents> for sys::hb_font_extents_t {
+    fn from(extents: FontExtents) -> Self {
+        sys::hb_font_extents_t {
+            ascender: extents.ascender,
+            descender: extents.descender,
+            line_gap: extents.line_gap,
+            reserved9: 0,
+            reserved8: 0,
+            reserved7: 0,
+            reserved6: 0,
+            reserved5: 0,
+            reserved4: 0,
+            reserved3: 0,
+            reserved2: 0,
+            reserved1: 0,
+        }
+    }
+}
+
+/// The extents of a glyph, in font units scaled by the font's scale.
+///
+/// The bearings are relative to the glyph origin. Note that `height` is
+/// negative for glyphs extending below `y_bearing`, as the y axis points
+/// up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GlyphExtents {
+    /// Distance from the x-origin to the left extremum of the glyph.
+    pub x_bearing: i32,
+    /// Distance from the top extremum of the glyph to the y-origin.
+    pub y_bearing: i32,
+    /// Distance from the left extremum of the glyph to the right extremum.
+    pub width: i32,
+    /// Distance from the top extremum of the glyph to the bottom extremum.
+    pub height: i32,
+}
+
+impl From<sys::hb_glyph_extents_t> for GlyphExtents {
+    fn from(extents: sys::hb_glyph_extents_t) -> Self {
+        GlyphExtents {
+            x_bearing: extents.x_bearing,
+            y_bearing: extents.y_bearing,
+            width: extents.width,
+            height: extents.height,
+        }
+    }
+}
+
+impl From<GlyphExtents> for sys::hb_glyph_extents_t {
+    fn from(extents: GlyphExtents) -> Self {
+        sys::hb_glyph_extents_t {
+            x_bearing: extents.x_bearing,
+            y_bearing: extents.y_bearing,
+            width: extents.width,
+            height: extents.height,
+        }
+    }
+}
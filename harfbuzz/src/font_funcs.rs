@@ -0,0 +1,626 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::os::raw::{c_char, c_int, c_uint, c_void};
+use std::{ffi, ptr, slice, str};
+use sys;
+
+use crate::draw::{self, DrawSink, HbDrawSink, TransformSink};
+use crate::paint::{self, HbPaintSink, PaintSink};
+use crate::{Color, Font, FontExtents, GlyphExtents, Strided, StridedMut};
+
+/// Font callbacks used by HarfBuzz to query glyphs and metrics.
+///
+/// By default a `Font` answers these queries from the tables of its
+/// face. Implementing this trait and installing it with
+/// [`Font::set_funcs`] makes HarfBuzz query the implementation
+/// instead, e.g. to use an external glyph cache or metrics source.
+///
+/// Every method has a default implementation which delegates to the
+/// parent font, scaling the result from the parent's scale to the
+/// font's scale, just like HarfBuzz does for callbacks that are not set.
+/// The parent of a font created with [`Font::new`] is the empty font,
+/// which has no glyphs: use [`Font::new_sub_font`] to override only some
+/// of the queries of another font.
+///
+/// ```
+/// # use harfbuzz::{hb_shape, Blob, Buffer, Face, Font, FontExtents, FontFuncs};
+/// /// A font mapping each character to the glyph of the same index, with a
+/// /// fixed advance.
+/// struct Monospace;
+///
+/// impl FontFuncs for Monospace {
+///     fn nominal_glyph(&self, _font: &Font, unicode: u32) -> Option<u32> {
+///         Some(unicode)
+///     }
+///
+///     fn glyph_h_advance(&self, _font: &Font, _glyph: u32) -> i32 {
+///         600
+///     }
+///
+///     fn font_h_extents(&self, _font: &Font) -> Option<FontExtents> {
+///         Some(FontExtents { ascender: 800, descender: -200, line_gap: 100 })
+///     }
+/// }
+///
+/// let face = Face::new(Blob::new_read_only(&[]), 0);
+/// let mut font = Font::new(&face);
+/// font.set_funcs(Monospace);
+///
+/// let mut buffer = Buffer::with("abc");
+/// buffer.guess_segment_properties();
+/// let shaped = hb_shape(&font, buffer, &[]);
+/// let glyphs: Vec<_> = shaped.get_glyph_infos().iter().map(|info| info.index()).collect();
+/// let advances: Vec<_> = shaped.get_glyph_positions().iter().map(|pos| pos.x_advance()).collect();
+/// assert_eq!(glyphs, vec![97, 98, 99]);
+/// assert_eq!(advances, vec![600, 600, 600]);
+/// assert_eq!(
+///     font.get_h_extents(),
+///     Some(FontExtents { ascender: 800, descender: -200, line_gap: 100 })
+/// );
+/// ```
+///
+/// [`Font::set_funcs`]: crate::Font::set_funcs
+/// [`Font::new`]: crate::Font::new
+/// [`Font::new_sub_font`]: crate::Font::new_sub_font
+pub trait FontFuncs {
+    /// Fetches the font-wide extents for horizontal text.
+    fn font_h_extents(&self, font: &Font) -> Option<FontExtents> {
+        let mut extents = MaybeUninit::uninit();
+        unsafe {
+            if sys::hb_font_get_h_extents(parent(font), extents.as_mut_ptr()) == 0 {
+                return None;
+            }
+            let extents = extents.assume_init();
+            Some(FontExtents {
+                ascender: scale_y(font, extents.ascender),
+                descender: scale_y(font, extents.descender),
+                line_gap: scale_y(font, extents.line_gap),
+            })
+        }
+    }
+
+    /// Fetches the font-wide extents for vertical text.
+    fn font_v_extents(&self, font: &Font) -> Option<FontExtents> {
+        let mut extents = MaybeUninit::uninit();
+        unsafe {
+            if sys::hb_font_get_v_extents(parent(font), extents.as_mut_ptr()) == 0 {
+                return None;
+            }
+            let extents = extents.assume_init();
+            Some(FontExtents {
+                ascender: scale_x(font, extents.ascender),
+                descender: scale_x(font, extents.descender),
+                line_gap: scale_x(font, extents.line_gap),
+            })
+        }
+    }
+
+    /// Fetches the nominal glyph ID for a Unicode code point, if the font
+    /// has one.
+    fn nominal_glyph(&self, font: &Font, unicode: u32) -> Option<u32> {
+        let mut glyph = 0;
+        unsafe { sys::hb_font_get_nominal_glyph(parent(font), unicode, &mut glyph) != 0 }.then_some(glyph)
+    }
+
+    /// Fetches the nominal glyph IDs for a run of Unicode code points,
+    /// stopping at the first code point the font has no glyph for.
+    ///
+    /// Returns the number of glyphs written. The default implementation
+    /// calls [`nominal_glyph`](#method.nominal_glyph) for each code point.
+    fn nominal_glyphs(&self, font: &Font, unicodes: Strided<u32>, mut glyphs: StridedMut<u32>) -> usize {
+        for (index, &unicode) in unicodes.iter().enumerate() {
+            match (self.nominal_glyph(font, unicode), glyphs.get_mut(index)) {
+                (Some(glyph), Some(out)) => *out = glyph,
+                _ => return index,
+            }
+        }
+        unicodes.len()
+    }
+
+    /// Fetches the glyph ID for a Unicode code point followed by a
+    /// variation selector, if the font has one.
+    fn variation_glyph(&self, font: &Font, unicode: u32, variation_selector: u32) -> Option<u32> {
+        let mut glyph = 0;
+        unsafe { sys::hb_font_get_variation_glyph(parent(font), unicode, variation_selector, &mut glyph) != 0 }
+            .then_some(glyph)
+    }
+
+    /// Fetches the advance of a glyph for horizontal text.
+    fn glyph_h_advance(&self, font: &Font, glyph: u32) -> i32 {
+        scale_x(font, unsafe { sys::hb_font_get_glyph_h_advance(parent(font), glyph) })
+    }
+
+    /// Fetches the advance of a glyph for vertical text.
+    fn glyph_v_advance(&self, font: &Font, glyph: u32) -> i32 {
+        scale_y(font, unsafe { sys::hb_font_get_glyph_v_advance(parent(font), glyph) })
+    }
+
+    /// Fetches the advances of a run of glyphs for horizontal text.
+    ///
+    /// The default implementation calls
+    /// [`glyph_h_advance`](#method.glyph_h_advance) for each glyph.
+    fn glyph_h_advances(&self, font: &Font, glyphs: Strided<u32>, mut advances: StridedMut<i32>) {
+        for (index, &glyph) in glyphs.iter().enumerate() {
+            if let Some(advance) = advances.get_mut(index) {
+                *advance = self.glyph_h_advance(font, glyph);
+            }
+        }
+    }
+
+    /// Fetches the advances of a run of glyphs for vertical text.
+    ///
+    /// The default implementation calls
+    /// [`glyph_v_advance`](#method.glyph_v_advance) for each glyph.
+    fn glyph_v_advances(&self, font: &Font, glyphs: Strided<u32>, mut advances: StridedMut<i32>) {
+        for (index, &glyph) in glyphs.iter().enumerate() {
+            if let Some(advance) = advances.get_mut(index) {
+                *advance = self.glyph_v_advance(font, glyph);
+            }
+        }
+    }
+
+    /// Fetches the (x, y) origin of a glyph for horizontal text.
+    fn glyph_h_origin(&self, font: &Font, glyph: u32) -> Option<(i32, i32)> {
+        let (mut x, mut y) = (0, 0);
+        unsafe { sys::hb_font_get_glyph_h_origin(parent(font), glyph, &mut x, &mut y) != 0 }
+            .then(|| (scale_x(font, x), scale_y(font, y)))
+    }
+
+    /// Fetches the (x, y) origin of a glyph for vertical text.
+    fn glyph_v_origin(&self, font: &Font, glyph: u32) -> Option<(i32, i32)> {
+        let (mut x, mut y) = (0, 0);
+        unsafe { sys::hb_font_get_glyph_v_origin(parent(font), glyph, &mut x, &mut y) != 0 }
+            .then(|| (scale_x(font, x), scale_y(font, y)))
+    }
+
+    /// Fetches the kerning adjustment between two glyphs for horizontal
+    /// text.
+    fn glyph_h_kerning(&self, font: &Font, left_glyph: u32, right_glyph: u32) -> i32 {
+        scale_x(font, unsafe { sys::hb_font_get_glyph_h_kerning(parent(font), left_glyph, right_glyph) })
+    }
+
+    /// Fetches the extents of a glyph.
+    fn glyph_extents(&self, font: &Font, glyph: u32) -> Option<GlyphExtents> {
+        let mut extents = MaybeUninit::uninit();
+        unsafe {
+            if sys::hb_font_get_glyph_extents(parent(font), glyph, extents.as_mut_ptr()) == 0 {
+                return None;
+            }
+            let extents = extents.assume_init();
+            Some(GlyphExtents {
+                x_bearing: scale_x(font, extents.x_bearing),
+                y_bearing: scale_y(font, extents.y_bearing),
+                width: scale_x(font, extents.width),
+                height: scale_y(font, extents.height),
+            })
+        }
+    }
+
+    /// Fetches the (x, y) position of a point of a glyph's contours.
+    fn glyph_contour_point(&self, font: &Font, glyph: u32, point_index: u32) -> Option<(i32, i32)> {
+        let (mut x, mut y) = (0, 0);
+        unsafe { sys::hb_font_get_glyph_contour_point(parent(font), glyph, point_index, &mut x, &mut y) != 0 }
+            .then(|| (scale_x(font, x), scale_y(font, y)))
+    }
+
+    /// Fetches the name of a glyph.
+    fn glyph_name(&self, font: &Font, glyph: u32) -> Option<String> {
+        let mut buf: [c_char; 256] = [0; 256];
+        unsafe {
+            if sys::hb_font_get_glyph_name(parent(font), glyph, buf.as_mut_ptr(), buf.len() as c_uint) == 0 {
+                return None;
+            }
+            ffi::CStr::from_ptr(buf.as_ptr()).to_str().ok().map(String::from)
+        }
+    }
+
+    /// Fetches the glyph ID for a glyph name.
+    fn glyph_from_name(&self, font: &Font, name: &str) -> Option<u32> {
+        let mut glyph = 0;
+        unsafe {
+            sys::hb_font_get_glyph_from_name(
+                parent(font),
+                name.as_ptr() as *const c_char,
+                name.len() as c_int,
+                &mut glyph,
+            ) != 0
+        }
+        .then_some(glyph)
+    }
+
+    /// Draws the outline of a glyph into `sink`.
+    fn draw_glyph(&self, font: &Font, glyph: u32, sink: &mut dyn DrawSink) {
+        let (x_scale, y_scale, slant) = parent_transform(font);
+        // Transform outlines drawn by the parent font to the font's scale
+        // and synthetic slant.
+        let mut sink = TransformSink {
+            sink,
+            x_scale,
+            y_scale,
+            slant,
+            dx: 0.0,
+            dy: 0.0,
+        };
+        draw::draw_glyph(parent(font), glyph, &mut sink);
+    }
+
+    /// Paints a color glyph into `sink`, with the colors of the palette
+    /// at `palette_index` and the given foreground color.
+    fn paint_glyph(&self, font: &Font, glyph: u32, sink: &mut dyn PaintSink, palette_index: u32, foreground: Color) {
+        let (x_scale, y_scale, slant) = parent_transform(font);
+        sink.push_transform(x_scale, 0.0, slant, y_scale, 0.0, 0.0);
+        paint::paint_glyph(parent(font), glyph, sink, palette_index, foreground);
+        sink.pop_transform();
+    }
+}
+
+/// Installs `funcs` as the font functions of the raw `font`.
+pub(crate) fn set_font_funcs<F: FontFuncs + 'static>(font: *mut sys::hb_font_t, funcs: F) {
+    unsafe {
+        let ffuncs = sys::hb_font_funcs_create();
+        sys::hb_font_funcs_set_font_h_extents_func(ffuncs, Some(font_h_extents::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_font_v_extents_func(ffuncs, Some(font_v_extents::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_nominal_glyph_func(ffuncs, Some(nominal_glyph::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_nominal_glyphs_func(ffuncs, Some(nominal_glyphs::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_variation_glyph_func(ffuncs, Some(variation_glyph::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_h_advance_func(ffuncs, Some(glyph_h_advance::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_v_advance_func(ffuncs, Some(glyph_v_advance::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_h_advances_func(ffuncs, Some(glyph_h_advances::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_v_advances_func(ffuncs, Some(glyph_v_advances::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_h_origin_func(ffuncs, Some(glyph_h_origin::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_v_origin_func(ffuncs, Some(glyph_v_origin::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_h_kerning_func(ffuncs, Some(glyph_h_kerning::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_extents_func(ffuncs, Some(glyph_extents::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_contour_point_func(
+            ffuncs,
+            Some(glyph_contour_point::<F>),
+            ptr::null_mut(),
+            None,
+        );
+        sys::hb_font_funcs_set_glyph_name_func(ffuncs, Some(glyph_name::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_glyph_from_name_func(ffuncs, Some(glyph_from_name::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_draw_glyph_func(ffuncs, Some(draw_glyph::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_set_paint_glyph_func(ffuncs, Some(paint_glyph::<F>), ptr::null_mut(), None);
+        sys::hb_font_funcs_make_immutable(ffuncs);
+
+        let font_data = Box::into_raw(Box::new(funcs));
+        sys::hb_font_set_funcs(font, ffuncs, font_data as *mut c_void, Some(destroy::<F>));
+        // The font holds its own reference.
+        sys::hb_font_funcs_destroy(ffuncs);
+    }
+}
+
+/// The parent of `font`, which the default methods delegate to.
+fn parent(font: &Font) -> *mut sys::hb_font_t {
+    unsafe { sys::hb_font_get_parent(font.as_ptr()) }
+}
+
+/// Scales a horizontal distance from the parent's scale to `font`'s.
+fn scale_x(font: &Font, v: i32) -> i32 {
+    let (mut x_scale, mut parent_x_scale) = (0, 0);
+    unsafe {
+        sys::hb_font_get_scale(font.as_ptr(), &mut x_scale, ptr::null_mut());
+        sys::hb_font_get_scale(parent(font), &mut parent_x_scale, ptr::null_mut());
+    }
+    scale(v, x_scale, parent_x_scale)
+}
+
+/// Scales a vertical distance from the parent's scale to `font`'s.
+fn scale_y(font: &Font, v: i32) -> i32 {
+    let (mut y_scale, mut parent_y_scale) = (0, 0);
+    unsafe {
+        sys::hb_font_get_scale(font.as_ptr(), ptr::null_mut(), &mut y_scale);
+        sys::hb_font_get_scale(parent(font), ptr::null_mut(), &mut parent_y_scale);
+    }
+    scale(v, y_scale, parent_y_scale)
+}
+
+/// The x and y scales and the slant transforming the parent's outlines to
+/// `font`'s scale and synthetic slant.
+fn parent_transform(font: &Font) -> (f32, f32, f32) {
+    let parent = parent(font);
+    let (mut x_scale, mut y_scale) = (0, 0);
+    let (mut parent_x_scale, mut parent_y_scale) = (0, 0);
+    let (slant, parent_slant) = unsafe {
+        sys::hb_font_get_scale(font.as_ptr(), &mut x_scale, &mut y_scale);
+        sys::hb_font_get_scale(parent, &mut parent_x_scale, &mut parent_y_scale);
+        (sys::hb_font_get_synthetic_slant(font.as_ptr()), sys::hb_font_get_synthetic_slant(parent))
+    };
+    let ratio = |scale: i32, parent_scale: i32| {
+        if parent_scale != 0 {
+            scale as f32 / parent_scale as f32
+        } else {
+            0.0
+        }
+    };
+    (
+        ratio(x_scale, parent_x_scale),
+        ratio(y_scale, parent_y_scale),
+        (slant - parent_slant) * ratio(x_scale, parent_y_scale),
+    )
+}
+
+fn scale(v: i32, scale: i32, parent_scale: i32) -> i32 {
+    if parent_scale != 0 && parent_scale != scale {
+        (v as i64 * scale as i64 / parent_scale as i64) as i32
+    } else {
+        v
+    }
+}
+
+/// Borrows the font passed to a callback, without taking ownership.
+pub(crate) unsafe fn borrow_font(font: *mut sys::hb_font_t) -> ManuallyDrop<Font<'static>> {
+    ManuallyDrop::new(Font::from_raw(font))
+}
+
+unsafe extern "C" fn destroy<F: FontFuncs>(font_data: *mut c_void) {
+    drop(Box::from_raw(font_data as *mut F));
+}
+
+unsafe extern "C" fn font_h_extents<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    extents: *mut sys::hb_font_extents_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.font_h_extents(&borrow_font(font));
+    *extents = result.unwrap_or_default().into();
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn font_v_extents<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    extents: *mut sys::hb_font_extents_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.font_v_extents(&borrow_font(font));
+    *extents = result.unwrap_or_default().into();
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn nominal_glyph<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    unicode: sys::hb_codepoint_t,
+    glyph: *mut sys::hb_codepoint_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.nominal_glyph(&borrow_font(font), unicode);
+    *glyph = result.unwrap_or(0);
+    result.is_some() as sys::hb_bool_t
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn nominal_glyphs<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    count: c_uint,
+    first_unicode: *const sys::hb_codepoint_t,
+    unicode_stride: c_uint,
+    first_glyph: *mut sys::hb_codepoint_t,
+    glyph_stride: c_uint,
+    _user_data: *mut c_void,
+) -> c_uint {
+    let funcs = &*(font_data as *const F);
+    let unicodes = Strided::from_raw_parts(first_unicode, count as usize, unicode_stride as usize);
+    let glyphs = StridedMut::from_raw_parts(first_glyph, count as usize, glyph_stride as usize);
+    funcs.nominal_glyphs(&borrow_font(font), unicodes, glyphs) as c_uint
+}
+
+unsafe extern "C" fn variation_glyph<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    unicode: sys::hb_codepoint_t,
+    variation_selector: sys::hb_codepoint_t,
+    glyph: *mut sys::hb_codepoint_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.variation_glyph(&borrow_font(font), unicode, variation_selector);
+    *glyph = result.unwrap_or(0);
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn glyph_h_advance<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    _user_data: *mut c_void,
+) -> sys::hb_position_t {
+    let funcs = &*(font_data as *const F);
+    funcs.glyph_h_advance(&borrow_font(font), glyph)
+}
+
+unsafe extern "C" fn glyph_v_advance<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    _user_data: *mut c_void,
+) -> sys::hb_position_t {
+    let funcs = &*(font_data as *const F);
+    funcs.glyph_v_advance(&borrow_font(font), glyph)
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn glyph_h_advances<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    count: c_uint,
+    first_glyph: *const sys::hb_codepoint_t,
+    glyph_stride: c_uint,
+    first_advance: *mut sys::hb_position_t,
+    advance_stride: c_uint,
+    _user_data: *mut c_void,
+) {
+    let funcs = &*(font_data as *const F);
+    let glyphs = Strided::from_raw_parts(first_glyph, count as usize, glyph_stride as usize);
+    let advances = StridedMut::from_raw_parts(first_advance, count as usize, advance_stride as usize);
+    funcs.glyph_h_advances(&borrow_font(font), glyphs, advances);
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn glyph_v_advances<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    count: c_uint,
+    first_glyph: *const sys::hb_codepoint_t,
+    glyph_stride: c_uint,
+    first_advance: *mut sys::hb_position_t,
+    advance_stride: c_uint,
+    _user_data: *mut c_void,
+) {
+    let funcs = &*(font_data as *const F);
+    let glyphs = Strided::from_raw_parts(first_glyph, count as usize, glyph_stride as usize);
+    let advances = StridedMut::from_raw_parts(first_advance, count as usize, advance_stride as usize);
+    funcs.glyph_v_advances(&borrow_font(font), glyphs, advances);
+}
+
+unsafe extern "C" fn glyph_h_origin<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    x: *mut sys::hb_position_t,
+    y: *mut sys::hb_position_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.glyph_h_origin(&borrow_font(font), glyph);
+    (*x, *y) = result.unwrap_or((0, 0));
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn glyph_v_origin<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    x: *mut sys::hb_position_t,
+    y: *mut sys::hb_position_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.glyph_v_origin(&borrow_font(font), glyph);
+    (*x, *y) = result.unwrap_or((0, 0));
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn glyph_h_kerning<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    left_glyph: sys::hb_codepoint_t,
+    right_glyph: sys::hb_codepoint_t,
+    _user_data: *mut c_void,
+) -> sys::hb_position_t {
+    let funcs = &*(font_data as *const F);
+    funcs.glyph_h_kerning(&borrow_font(font), left_glyph, right_glyph)
+}
+
+unsafe extern "C" fn glyph_extents<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    extents: *mut sys::hb_glyph_extents_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.glyph_extents(&borrow_font(font), glyph);
+    *extents = result.unwrap_or_default().into();
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn glyph_contour_point<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    point_index: c_uint,
+    x: *mut sys::hb_position_t,
+    y: *mut sys::hb_position_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.glyph_contour_point(&borrow_font(font), glyph, point_index);
+    (*x, *y) = result.unwrap_or((0, 0));
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn glyph_name<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    name: *mut c_char,
+    size: c_uint,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    let result = funcs.glyph_name(&borrow_font(font), glyph);
+    if size > 0 {
+        // Truncate the name to fit, leaving room for the nul.
+        let bytes = result.as_deref().unwrap_or("").as_bytes();
+        let length = bytes.len().min(size as usize - 1);
+        ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, name, length);
+        *name.add(length) = 0;
+    }
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn glyph_from_name<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    name: *const c_char,
+    len: c_int,
+    glyph: *mut sys::hb_codepoint_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let funcs = &*(font_data as *const F);
+    // A negative length means the name is nul-terminated.
+    let name = if len < 0 {
+        ffi::CStr::from_ptr(name).to_bytes()
+    } else {
+        slice::from_raw_parts(name as *const u8, len as usize)
+    };
+    let result = str::from_utf8(name)
+        .ok()
+        .and_then(|name| funcs.glyph_from_name(&borrow_font(font), name));
+    *glyph = result.unwrap_or(0);
+    result.is_some() as sys::hb_bool_t
+}
+
+unsafe extern "C" fn draw_glyph<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    draw_funcs: *mut sys::hb_draw_funcs_t,
+    draw_data: *mut c_void,
+    _user_data: *mut c_void,
+) {
+    let funcs = &*(font_data as *const F);
+    let mut sink = HbDrawSink::new(draw_funcs, draw_data);
+    funcs.draw_glyph(&borrow_font(font), glyph, &mut sink);
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn paint_glyph<F: FontFuncs>(
+    font: *mut sys::hb_font_t,
+    font_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    paint_funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    palette_index: c_uint,
+    foreground: sys::hb_color_t,
+    _user_data: *mut c_void,
+) {
+    let funcs = &*(font_data as *const F);
+    let mut sink = HbPaintSink::new(paint_funcs, paint_data);
+    funcs.paint_glyph(&borrow_font(font), glyph, &mut sink, palette_index, foreground.into());
+}
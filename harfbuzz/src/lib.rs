@@ -33,20 +33,60 @@ mod blob;
 pub use self::blob::Blob;
 
 mod face;
-pub use self::face::Face;
+pub use self::face::{Face, TableTags};
+
+mod face_builder;
+pub use self::face_builder::FaceBuilder;
+
+mod name;
+pub use self::name::{NameEntry, NameId};
 
 mod font;
-pub use self::font::Font;
+pub use self::font::{Font, FontExtents, GlyphExtents};
+
+mod metrics;
+pub use self::metrics::MetricsTag;
+
+mod font_funcs;
+pub use self::font_funcs::FontFuncs;
+
+mod draw;
+pub use self::draw::{DrawSink, OutlinePath, PathCommand};
+
+mod paint;
+pub use self::paint::{ColorLine, ColorStop, CompositeMode, PaintExtend, PaintImage, PaintSink};
+
+mod color;
+pub use self::color::{Color, ColorLayer, ColorLayers, ColorPalette, PaletteFlags, PngGlyph};
+
+mod set;
+pub use self::set::{Set, SetIter, SetRanges};
+
+mod strided;
+pub use self::strided::{Strided, StridedMut};
+
+mod paging;
 
 mod shape;
 pub use self::shape::{hb_shape, ShapedBuffer};
 
+mod svg;
+pub use self::svg::{shaped_buffer_to_svg, SvgPaths};
+
+#[cfg(feature = "raster")]
+mod raster;
+#[cfg(feature = "raster")]
+pub use self::raster::{render_glyph, render_run, GrayImage, MAX_IMAGE_PIXELS};
+
 mod feature;
 pub use self::feature::Feature;
 
 mod tag;
 pub use self::tag::Tag;
 
+mod variation;
+pub use self::variation::{AxisFlags, AxisInfo, NamedInstance, Variation};
+
 /// Describe the possible errore for functions that return a `Result`.
 #[derive(Debug)]
 pub enum ErrorKind {
@@ -0,0 +1,123 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use sys;
+
+/// A font-wide metric, as found in the OpenType `OS/2`, `hhea`, `vhea` and
+/// `post` tables and varied by the `MVAR` table.
+///
+/// See [`Font::get_metrics_position`](crate::Font::get_metrics_position).
+///
+/// This maps to the [`hb_ot_metrics_tag_t`] from [`harfbuzz-sys`], and can
+/// be converted to it using the [`Into`] trait:
+///
+/// ```
+/// # use harfbuzz::{sys, MetricsTag};
+/// let tag: sys::hb_ot_metrics_tag_t = MetricsTag::XHeight.into();
+/// assert_eq!(tag, sys::HB_OT_METRICS_TAG_X_HEIGHT);
+/// ```
+///
+/// [`hb_ot_metrics_tag_t`]: ../harfbuzz_sys/type.hb_ot_metrics_tag_t.html
+/// [`harfbuzz-sys`]: ../harfbuzz_sys/index.html
+/// [`Into`]: https://doc.rust-lang.org/std/convert/trait.Into.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricsTag {
+    /// The horizontal ascender.
+    HorizontalAscender,
+    /// The horizontal descender.
+    HorizontalDescender,
+    /// The horizontal line gap.
+    HorizontalLineGap,
+    /// The horizontal clipping ascent.
+    HorizontalClippingAscent,
+    /// The horizontal clipping descent.
+    HorizontalClippingDescent,
+    /// The vertical ascender.
+    VerticalAscender,
+    /// The vertical descender.
+    VerticalDescender,
+    /// The vertical line gap.
+    VerticalLineGap,
+    /// The rise of the caret slope for horizontal text.
+    HorizontalCaretRise,
+    /// The run of the caret slope for horizontal text.
+    HorizontalCaretRun,
+    /// The caret offset for horizontal text.
+    HorizontalCaretOffset,
+    /// The rise of the caret slope for vertical text.
+    VerticalCaretRise,
+    /// The run of the caret slope for vertical text.
+    VerticalCaretRun,
+    /// The caret offset for vertical text.
+    VerticalCaretOffset,
+    /// The height of lowercase letters such as "x".
+    XHeight,
+    /// The height of capital letters.
+    CapHeight,
+    /// The horizontal size of subscripts.
+    SubscriptEmXSize,
+    /// The vertical size of subscripts.
+    SubscriptEmYSize,
+    /// The horizontal offset of subscripts.
+    SubscriptEmXOffset,
+    /// The vertical offset of subscripts.
+    SubscriptEmYOffset,
+    /// The horizontal size of superscripts.
+    SuperscriptEmXSize,
+    /// The vertical size of superscripts.
+    SuperscriptEmYSize,
+    /// The horizontal offset of superscripts.
+    SuperscriptEmXOffset,
+    /// The vertical offset of superscripts.
+    SuperscriptEmYOffset,
+    /// The thickness of the strikeout stroke.
+    StrikeoutSize,
+    /// The position of the strikeout stroke above the baseline.
+    StrikeoutOffset,
+    /// The thickness of the underline stroke.
+    UnderlineSize,
+    /// The position of the underline stroke relative to the baseline,
+    /// usually negative.
+    UnderlineOffset,
+}
+
+impl From<MetricsTag> for sys::hb_ot_metrics_tag_t {
+    fn from(tag: MetricsTag) -> Self {
+        match tag {
+            MetricsTag::HorizontalAscender => sys::HB_OT_METRICS_TAG_HORIZONTAL_ASCENDER,
+            MetricsTag::HorizontalDescender => sys::HB_OT_METRICS_TAG_HORIZONTAL_DESCENDER,
+            MetricsTag::HorizontalLineGap => sys::HB_OT_METRICS_TAG_HORIZONTAL_LINE_GAP,
+            MetricsTag::HorizontalClippingAscent => sys::HB_OT_METRICS_TAG_HORIZONTAL_CLIPPING_ASCENT,
+            MetricsTag::HorizontalClippingDescent => sys::HB_OT_METRICS_TAG_HORIZONTAL_CLIPPING_DESCENT,
+            MetricsTag::VerticalAscender => sys::HB_OT_METRICS_TAG_VERTICAL_ASCENDER,
+            MetricsTag::VerticalDescender => sys::HB_OT_METRICS_TAG_VERTICAL_DESCENDER,
+            MetricsTag::VerticalLineGap => sys::HB_OT_METRICS_TAG_VERTICAL_LINE_GAP,
+            MetricsTag::HorizontalCaretRise => sys::HB_OT_METRICS_TAG_HORIZONTAL_CARET_RISE,
+            MetricsTag::HorizontalCaretRun => sys::HB_OT_METRICS_TAG_HORIZONTAL_CARET_RUN,
+            MetricsTag::HorizontalCaretOffset => sys::HB_OT_METRICS_TAG_HORIZONTAL_CARET_OFFSET,
+            MetricsTag::VerticalCaretRise => sys::HB_OT_METRICS_TAG_VERTICAL_CARET_RISE,
+            MetricsTag::VerticalCaretRun => sys::HB_OT_METRICS_TAG_VERTICAL_CARET_RUN,
+            MetricsTag::VerticalCaretOffset => sys::HB_OT_METRICS_TAG_VERTICAL_CARET_OFFSET,
+            MetricsTag::XHeight => sys::HB_OT_METRICS_TAG_X_HEIGHT,
+            MetricsTag::CapHeight => sys::HB_OT_METRICS_TAG_CAP_HEIGHT,
+            MetricsTag::SubscriptEmXSize => sys::HB_OT_METRICS_TAG_SUBSCRIPT_EM_X_SIZE,
+            MetricsTag::SubscriptEmYSize => sys::HB_OT_METRICS_TAG_SUBSCRIPT_EM_Y_SIZE,
+            MetricsTag::SubscriptEmXOffset => sys::HB_OT_METRICS_TAG_SUBSCRIPT_EM_X_OFFSET,
+            MetricsTag::SubscriptEmYOffset => sys::HB_OT_METRICS_TAG_SUBSCRIPT_EM_Y_OFFSET,
+            MetricsTag::SuperscriptEmXSize => sys::HB_OT_METRICS_TAG_SUPERSCRIPT_EM_X_SIZE,
+            MetricsTag::SuperscriptEmYSize => sys::HB_OT_METRICS_TAG_SUPERSCRIPT_EM_Y_SIZE,
+            MetricsTag::SuperscriptEmXOffset => sys::HB_OT_METRICS_TAG_SUPERSCRIPT_EM_X_OFFSET,
+            MetricsTag::SuperscriptEmYOffset => sys::HB_OT_METRICS_TAG_SUPERSCRIPT_EM_Y_OFFSET,
+            MetricsTag::StrikeoutSize => sys::HB_OT_METRICS_TAG_STRIKEOUT_SIZE,
+            MetricsTag::StrikeoutOffset => sys::HB_OT_METRICS_TAG_STRIKEOUT_OFFSET,
+            MetricsTag::UnderlineSize => sys::HB_OT_METRICS_TAG_UNDERLINE_SIZE,
+            MetricsTag::UnderlineOffset => sys::HB_OT_METRICS_TAG_UNDERLINE_OFFSET,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use sys;
+
+use crate::Language;
+
+/// The ID of a string of the OpenType `name` table.
+///
+/// The standard IDs have their own variant, and font-specific IDs, which
+/// are 256 or more, are represented by `Other`. `NameId` can be converted
+/// to or from `hb_ot_name_id_t` using the [`From`] and [`Into`] traits.
+///
+/// `NameId`s compare and hash by their numeric value, so `Other` holding a
+/// standard ID is the same as that ID's variant:
+///
+/// ```
+/// # use harfbuzz::{sys, NameId};
+/// assert_eq!(NameId::from(sys::HB_OT_NAME_ID_FONT_FAMILY), NameId::FontFamily);
+/// assert_eq!(NameId::from(256), NameId::Other(256));
+/// assert_eq!(NameId::Other(1), NameId::FontFamily);
+/// assert_eq!(sys::hb_ot_name_id_t::from(NameId::PostScriptName), sys::HB_OT_NAME_ID_POSTSCRIPT_NAME);
+/// ```
+///
+/// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+/// [`Into`]: https://doc.rust-lang.org/std/convert/trait.Into.html
+#[derive(Copy, Clone, Debug)]
+pub enum NameId {
+    /// The copyright notice.
+    Copyright,
+    /// The font family name.
+    FontFamily,
+    /// The font subfamily name, such as "Bold".
+    FontSubfamily,
+    /// The unique font identifier.
+    UniqueId,
+    /// The full font name.
+    FullName,
+    /// The version string.
+    VersionString,
+    /// The PostScript name.
+    PostScriptName,
+    /// The trademark notice.
+    Trademark,
+    /// The name of the manufacturer.
+    Manufacturer,
+    /// The name of the designer.
+    Designer,
+    /// The description of the typeface.
+    Description,
+    /// The URL of the font vendor.
+    VendorUrl,
+    /// The URL of the designer.
+    DesignerUrl,
+    /// The license description.
+    License,
+    /// The URL of the license.
+    LicenseUrl,
+    /// The typographic family name, grouping more than four styles.
+    TypographicFamily,
+    /// The typographic subfamily name.
+    TypographicSubfamily,
+    /// The compatible full name, for Macintosh only.
+    MacFullName,
+    /// The sample text.
+    SampleText,
+    /// The PostScript CID findfont name.
+    CidFindfontName,
+    /// The weight-width-slope family name.
+    WwsFamily,
+    /// The weight-width-slope subfamily name.
+    WwsSubfamily,
+    /// The name of the light background palette.
+    LightBackground,
+    /// The name of the dark background palette.
+    DarkBackground,
+    /// The prefix of the PostScript names of variation instances.
+    VariationsPsPrefix,
+    /// Any other ID, such as a font-specific one.
+    Other(u32),
+}
+
+impl From<sys::hb_ot_name_id_t> for NameId {
+    fn from(name_id: sys::hb_ot_name_id_t) -> Self {
+        match name_id {
+            sys::HB_OT_NAME_ID_COPYRIGHT => NameId::Copyright,
+            sys::HB_OT_NAME_ID_FONT_FAMILY => NameId::FontFamily,
+            sys::HB_OT_NAME_ID_FONT_SUBFAMILY => NameId::FontSubfamily,
+            sys::HB_OT_NAME_ID_UNIQUE_ID => NameId::UniqueId,
+            sys::HB_OT_NAME_ID_FULL_NAME => NameId::FullName,
+            sys::HB_OT_NAME_ID_VERSION_STRING => NameId::VersionString,
+            sys::HB_OT_NAME_ID_POSTSCRIPT_NAME => NameId::PostScriptName,
+            sys::HB_OT_NAME_ID_TRADEMARK => NameId::Trademark,
+            sys::HB_OT_NAME_ID_MANUFACTURER => NameId::Manufacturer,
+            sys::HB_OT_NAME_ID_DESIGNER => NameId::Designer,
+            sys::HB_OT_NAME_ID_DESCRIPTION => NameId::Description,
+            sys::HB_OT_NAME_ID_VENDOR_URL => NameId::VendorUrl,
+            sys::HB_OT_NAME_ID_DESIGNER_URL => NameId::DesignerUrl,
+            sys::HB_OT_NAME_ID_LICENSE => NameId::License,
+            sys::HB_OT_NAME_ID_LICENSE_URL => NameId::LicenseUrl,
+            sys::HB_OT_NAME_ID_TYPOGRAPHIC_FAMILY => NameId::TypographicFamily,
+            sys::HB_OT_NAME_ID_TYPOGRAPHIC_SUBFAMILY => NameId::TypographicSubfamily,
+            sys::HB_OT_NAME_ID_MAC_FULL_NAME => NameId::MacFullName,
+            sys::HB_OT_NAME_ID_SAMPLE_TEXT => NameId::SampleText,
+            sys::HB_OT_NAME_ID_CID_FINDFONT_NAME => NameId::CidFindfontName,
+            sys::HB_OT_NAME_ID_WWS_FAMILY => NameId::WwsFamily,
+            sys::HB_OT_NAME_ID_WWS_SUBFAMILY => NameId::WwsSubfamily,
+            sys::HB_OT_NAME_ID_LIGHT_BACKGROUND => NameId::LightBackground,
+            sys::HB_OT_NAME_ID_DARK_BACKGROUND => NameId::DarkBackground,
+            sys::HB_OT_NAME_ID_VARIATIONS_PS_PREFIX => NameId::VariationsPsPrefix,
+            name_id => NameId::Other(name_id),
+        }
+    }
+}
+
+impl From<NameId> for sys::hb_ot_name_id_t {
+    fn from(name_id: NameId) -> Self {
+        match name_id {
+            NameId::Copyright => sys::HB_OT_NAME_ID_COPYRIGHT,
+            NameId::FontFamily => sys::HB_OT_NAME_ID_FONT_FAMILY,
+            NameId::FontSubfamily => sys::HB_OT_NAME_ID_FONT_SUBFAMILY,
+            NameId::UniqueId => sys::HB_OT_NAME_ID_UNIQUE_ID,
+            NameId::FullName => sys::HB_OT_NAME_ID_FULL_NAME,
+            NameId::VersionString => sys::HB_OT_NAME_ID_VERSION_STRING,
+            NameId::PostScriptName => sys::HB_OT_NAME_ID_POSTSCRIPT_NAME,
+            NameId::Trademark => sys::HB_OT_NAME_ID_TRADEMARK,
+            NameId::Manufacturer => sys::HB_OT_NAME_ID_MANUFACTURER,
+            NameId::Designer => sys::HB_OT_NAME_ID_DESIGNER,
+            NameId::Description => sys::HB_OT_NAME_ID_DESCRIPTION,
+            NameId::VendorUrl => sys::HB_OT_NAME_ID_VENDOR_URL,
+            NameId::DesignerUrl => sys::HB_OT_NAME_ID_DESIGNER_URL,
+            NameId::License => sys::HB_OT_NAME_ID_LICENSE,
+            NameId::LicenseUrl => sys::HB_OT_NAME_ID_LICENSE_URL,
+            NameId::TypographicFamily => sys::HB_OT_NAME_ID_TYPOGRAPHIC_FAMILY,
+            NameId::TypographicSubfamily => sys::HB_OT_NAME_ID_TYPOGRAPHIC_SUBFAMILY,
+            NameId::MacFullName => sys::HB_OT_NAME_ID_MAC_FULL_NAME,
+            NameId::SampleText => sys::HB_OT_NAME_ID_SAMPLE_TEXT,
+            NameId::CidFindfontName => sys::HB_OT_NAME_ID_CID_FINDFONT_NAME,
+            NameId::WwsFamily => sys::HB_OT_NAME_ID_WWS_FAMILY,
+            NameId::WwsSubfamily => sys::HB_OT_NAME_ID_WWS_SUBFAMILY,
+            NameId::LightBackground => sys::HB_OT_NAME_ID_LIGHT_BACKGROUND,
+            NameId::DarkBackground => sys::HB_OT_NAME_ID_DARK_BACKGROUND,
+            NameId::VariationsPsPrefix => sys::HB_OT_NAME_ID_VARIATIONS_PS_PREFIX,
+            NameId::Other(name_id) => name_id,
+        }
+    }
+}
+
+impl PartialEq for NameId {
+    fn eq(&self, other: &NameId) -> bool {
+        sys::hb_ot_name_id_t::from(*self) == sys::hb_ot_name_id_t::from(*other)
+    }
+}
+
+impl Eq for NameId {}
+
+impl PartialOrd for NameId {
+    fn partial_cmp(&self, other: &NameId) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NameId {
+    fn cmp(&self, other: &NameId) -> Ordering {
+        sys::hb_ot_name_id_t::from(*self).cmp(&sys::hb_ot_name_id_t::from(*other))
+    }
+}
+
+impl Hash for NameId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        sys::hb_ot_name_id_t::from(*self).hash(state)
+    }
+}
+
+/// A string of the OpenType `name` table, identified by its ID and
+/// language.
+///
+/// See [`Face::names`](crate::Face::names).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NameEntry {
+    /// The ID of the string.
+    pub name_id: NameId,
+    /// The language of the string.
+    pub language: Language,
+}
@@ -0,0 +1,53 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::os::raw::c_uint;
+
+/// Iterates over an array HarfBuzz returns in chunks, through a start
+/// offset, an in/out count and an output buffer, fetching a page of items
+/// at a time.
+pub(crate) struct Pages<T> {
+    offset: c_uint,
+    page: Vec<T>,
+    index: usize,
+}
+
+impl<T: Copy> Pages<T> {
+    /// The number of items fetched at a time.
+    const PAGE_SIZE: usize = 16;
+
+    pub(crate) fn new() -> Self {
+        Pages {
+            offset: 0,
+            page: Vec::with_capacity(Self::PAGE_SIZE),
+            index: 0,
+        }
+    }
+
+    /// Returns the next item, calling `fetch` with the start offset, the
+    /// count and the buffer of the next page when the current one is
+    /// exhausted.
+    ///
+    /// # Safety
+    ///
+    /// `fetch` must set the count, initially the capacity of the buffer, to
+    /// the number of items it wrote to the buffer.
+    pub(crate) unsafe fn next(&mut self, fetch: impl FnOnce(c_uint, &mut c_uint, *mut T)) -> Option<T> {
+        if self.index == self.page.len() {
+            let mut count = Self::PAGE_SIZE as c_uint;
+            fetch(self.offset, &mut count, self.page.as_mut_ptr());
+            self.page.set_len(count as usize);
+            self.offset += count;
+            self.index = 0;
+        }
+        let item = self.page.get(self.index)?;
+        self.index += 1;
+        Some(*item)
+    }
+}
@@ -0,0 +1,706 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::marker::PhantomData;
+use std::os::raw::{c_uint, c_void};
+use std::sync::OnceLock;
+use std::ptr;
+use sys;
+
+use crate::font_funcs::borrow_font;
+use crate::{Blob, Color, Font, GlyphExtents, Tag};
+
+/// A receiver of color glyph paint operations.
+///
+/// Color glyphs, such as those of the `COLR` table, are described as a
+/// graph of paint operations: transforms, clips and groups are pushed and
+/// popped around fills with solid colors, gradients or images. Coordinates
+/// are in font units scaled by the font's scale, with the y axis pointing
+/// up.
+///
+/// ```
+/// # use harfbuzz::{Color, ColorLine, CompositeMode, Face, Font, PaintImage, PaintSink, Tag};
+/// /// Records the kinds of paint operations.
+/// #[derive(Default)]
+/// struct Recorder(Vec<&'static str>);
+///
+/// impl PaintSink for Recorder {
+///     fn push_transform(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {
+///         self.0.push("push_transform");
+///     }
+///     fn pop_transform(&mut self) {
+///         self.0.push("pop_transform");
+///     }
+///     fn push_clip_glyph(&mut self, _glyph: u32, _font: &Font) {
+///         self.0.push("push_clip_glyph");
+///     }
+///     fn push_clip_rectangle(&mut self, _: f32, _: f32, _: f32, _: f32) {
+///         self.0.push("push_clip_rectangle");
+///     }
+///     fn pop_clip(&mut self) {
+///         self.0.push("pop_clip");
+///     }
+///     fn color(&mut self, _is_foreground: bool, _color: Color) {
+///         self.0.push("color");
+///     }
+///     fn image(&mut self, image: &PaintImage) -> bool {
+///         assert_eq!(image.format, Tag::from_string("png"));
+///         self.0.push("image");
+///         true
+///     }
+///     fn linear_gradient(&mut self, color_line: &ColorLine, _: (f32, f32), _: (f32, f32), _: (f32, f32)) {
+///         assert!(!color_line.color_stops().is_empty());
+///         self.0.push("linear_gradient");
+///     }
+///     fn radial_gradient(&mut self, _: &ColorLine, _: (f32, f32), _: f32, _: (f32, f32), _: f32) {
+///         self.0.push("radial_gradient");
+///     }
+///     fn sweep_gradient(&mut self, _: &ColorLine, _: (f32, f32), _: f32, _: f32) {
+///         self.0.push("sweep_gradient");
+///     }
+///     fn push_group(&mut self) {
+///         self.0.push("push_group");
+///     }
+///     fn pop_group(&mut self, _mode: CompositeMode) {
+///         self.0.push("pop_group");
+///     }
+/// }
+///
+/// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/test_glyphs-glyf_colr_1.ttf", 0).unwrap();
+/// let font = Font::new(&face);
+/// let mut recorder = Recorder::default();
+/// font.paint_glyph(6, &mut recorder, 0, Color::new(0, 0, 0, 255));
+/// assert_eq!(recorder.0.first(), Some(&"push_clip_rectangle"));
+/// assert!(recorder.0.contains(&"push_clip_glyph"));
+/// assert!(recorder.0.contains(&"linear_gradient"));
+/// assert_eq!(recorder.0.last(), Some(&"pop_clip"));
+///
+/// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/chromacheck-cbdt.ttf", 0).unwrap();
+/// let font = Font::new(&face);
+/// let mut recorder = Recorder::default();
+/// font.paint_glyph(1, &mut recorder, 0, Color::new(0, 0, 0, 255));
+/// assert_eq!(recorder.0, ["image"]);
+/// ```
+pub trait PaintSink {
+    /// Applies a transform to subsequent paint operations, until the
+    /// matching `pop_transform`.
+    ///
+    /// The transform maps (x, y) to (`xx` * x + `xy` * y + `dx`,
+    /// `yx` * x + `yy` * y + `dy`), and is applied after the current one.
+    fn push_transform(&mut self, xx: f32, yx: f32, xy: f32, yy: f32, dx: f32, dy: f32);
+
+    /// Undoes the last `push_transform`.
+    fn pop_transform(&mut self);
+
+    /// Clips subsequent paint operations to the outline of a glyph of
+    /// `font`, until the matching `pop_clip`.
+    ///
+    /// The outline can be obtained with [`Font::draw_glyph`].
+    ///
+    /// [`Font::draw_glyph`]: crate::Font::draw_glyph
+    fn push_clip_glyph(&mut self, glyph: u32, font: &Font);
+
+    /// Clips subsequent paint operations to a rectangle, until the
+    /// matching `pop_clip`.
+    fn push_clip_rectangle(&mut self, x_min: f32, y_min: f32, x_max: f32, y_max: f32);
+
+    /// Undoes the last `push_clip_glyph` or `push_clip_rectangle`.
+    fn pop_clip(&mut self);
+
+    /// Fills the current clip with a solid color.
+    ///
+    /// `is_foreground` is true if the color is the foreground color
+    /// passed to [`Font::paint_glyph`], with the alpha of the paint
+    /// applied.
+    ///
+    /// [`Font::paint_glyph`]: crate::Font::paint_glyph
+    fn color(&mut self, is_foreground: bool, color: Color);
+
+    /// Fills the current clip with an image, returning whether the image
+    /// was painted.
+    ///
+    /// By default images are not painted. The sink passed to
+    /// [`FontFuncs::paint_glyph`] forwards images to HarfBuzz, which does
+    /// not report whether they were painted, so it returns true for every
+    /// image.
+    ///
+    /// [`FontFuncs::paint_glyph`]: crate::FontFuncs::paint_glyph
+    fn image(&mut self, image: &PaintImage) -> bool {
+        let _ = image;
+        false
+    }
+
+    /// Fills the current clip with a linear gradient.
+    ///
+    /// The gradient runs from `p0` to `p1`, with the color lines
+    /// perpendicular to the line from `p0` to `p2`.
+    fn linear_gradient(&mut self, color_line: &ColorLine, p0: (f32, f32), p1: (f32, f32), p2: (f32, f32));
+
+    /// Fills the current clip with a radial gradient between the circle
+    /// of radius `r0` around `c0` and the one of radius `r1` around `c1`.
+    fn radial_gradient(&mut self, color_line: &ColorLine, c0: (f32, f32), r0: f32, c1: (f32, f32), r1: f32);
+
+    /// Fills the current clip with a sweep gradient around `center`,
+    /// between the angles `start_angle` and `end_angle`, in radians
+    /// counter-clockwise from the x axis.
+    fn sweep_gradient(&mut self, color_line: &ColorLine, center: (f32, f32), start_angle: f32, end_angle: f32);
+
+    /// Redirects subsequent paint operations to a new group, until the
+    /// matching `pop_group`.
+    fn push_group(&mut self);
+
+    /// Composites the last pushed group onto the previous one with the
+    /// given composite mode.
+    fn pop_group(&mut self, mode: CompositeMode);
+
+    /// Overrides a color of the palette used for painting, if desired.
+    ///
+    /// By default the colors of the palette are used.
+    fn custom_palette_color(&mut self, color_index: u32) -> Option<Color> {
+        let _ = color_index;
+        None
+    }
+}
+
+/// An image to fill the current clip with, as passed to
+/// [`PaintSink::image`].
+#[derive(Clone)]
+pub struct PaintImage {
+    /// The image data.
+    pub blob: Blob<'static>,
+    /// The width of the image, in pixels.
+    pub width: u32,
+    /// The height of the image, in pixels.
+    pub height: u32,
+    /// The format of the image, as a tag such as `png ` for PNG, `svg `
+    /// for SVG, or `BGRA` for premultiplied BGRA pixels.
+    pub format: Tag,
+    /// The synthetic slant to apply to the image.
+    pub slant: f32,
+    /// The extents of the glyph, which the image should fill, if known.
+    pub extents: Option<GlyphExtents>,
+}
+
+/// The colors of a gradient, as passed to the gradient methods of
+/// [`PaintSink`].
+pub struct ColorLine<'a> {
+    raw: *mut sys::hb_color_line_t,
+    phantom: PhantomData<&'a sys::hb_color_line_t>,
+}
+
+impl<'a> ColorLine<'a> {
+    /// The color stops of the gradient, sorted by offset.
+    ///
+    /// HarfBuzz returns the stops in font order, which need not be sorted;
+    /// stops at the same offset keep that order.
+    pub fn color_stops(&self) -> Vec<ColorStop> {
+        let mut stops: Vec<ColorStop> = unsafe {
+            let total = sys::hb_color_line_get_color_stops(self.raw, 0, ptr::null_mut(), ptr::null_mut());
+            let mut count = total;
+            let mut stops = Vec::with_capacity(total as usize);
+            sys::hb_color_line_get_color_stops(self.raw, 0, &mut count, stops.as_mut_ptr());
+            stops.set_len(count as usize);
+            stops.into_iter().map(ColorStop::from).collect()
+        };
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        stops
+    }
+
+    /// How the gradient extends outside of the offsets of its stops.
+    pub fn extend(&self) -> PaintExtend {
+        unsafe { sys::hb_color_line_get_extend(self.raw) }.into()
+    }
+}
+
+/// A color stop of a gradient.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorStop {
+    /// The position of the stop along the gradient.
+    pub offset: f32,
+    /// True if the color is the foreground color, with the alpha of the
+    /// stop applied.
+    pub is_foreground: bool,
+    /// The color of the stop.
+    pub color: Color,
+}
+
+impl From<sys::hb_color_stop_t> for ColorStop {
+    fn from(stop: sys::hb_color_stop_t) -> Self {
+        ColorStop {
+            offset: stop.offset,
+            is_foreground: stop.is_foreground != 0,
+            color: stop.color.into(),
+        }
+    }
+}
+
+/// How a gradient extends outside of the offsets of its color stops.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaintExtend {
+    /// The color of the closest stop is used.
+    Pad,
+    /// The color stops are repeated.
+    Repeat,
+    /// The color stops are repeated, reversing every other repetition.
+    Reflect,
+}
+
+impl From<sys::hb_paint_extend_t> for PaintExtend {
+    fn from(extend: sys::hb_paint_extend_t) -> Self {
+        match extend {
+            sys::HB_PAINT_EXTEND_REPEAT => PaintExtend::Repeat,
+            sys::HB_PAINT_EXTEND_REFLECT => PaintExtend::Reflect,
+            _ => PaintExtend::Pad,
+        }
+    }
+}
+
+/// How a group is composited onto the one below it, as passed to
+/// [`PaintSink::pop_group`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompositeMode {
+    /// Clears the destination layer.
+    Clear,
+    /// Replaces the destination layer with the source.
+    Src,
+    /// Ignores the source.
+    Dest,
+    /// Draws the source on top of the destination.
+    SrcOver,
+    /// Draws the destination on top of the source.
+    DestOver,
+    /// Draws the source where there was destination content.
+    SrcIn,
+    /// Keeps the destination only where there was source content.
+    DestIn,
+    /// Draws the source where there was no destination content.
+    SrcOut,
+    /// Keeps the destination only where there was no source content.
+    DestOut,
+    /// Draws the source on top of the destination content, only there.
+    SrcAtop,
+    /// Draws the destination on top of the source content, only there.
+    DestAtop,
+    /// Shows the source and destination where only one of them has content.
+    Xor,
+    /// Adds the source and destination.
+    Plus,
+    /// Multiplies the complements of the source and destination.
+    Screen,
+    /// Multiplies or screens, depending on the destination.
+    Overlay,
+    /// Keeps the darker of the source and destination.
+    Darken,
+    /// Keeps the lighter of the source and destination.
+    Lighten,
+    /// Brightens the destination to reflect the source.
+    ColorDodge,
+    /// Darkens the destination to reflect the source.
+    ColorBurn,
+    /// Multiplies or screens, depending on the source.
+    HardLight,
+    /// Darkens or lightens, depending on the source.
+    SoftLight,
+    /// Subtracts the darker of the source and destination from the lighter.
+    Difference,
+    /// Like `Difference`, with lower contrast.
+    Exclusion,
+    /// Multiplies the source and destination.
+    Multiply,
+    /// Uses the hue of the source with the saturation and luminosity of the destination.
+    HslHue,
+    /// Uses the saturation of the source with the hue and luminosity of the destination.
+    HslSaturation,
+    /// Uses the hue and saturation of the source with the luminosity of the destination.
+    HslColor,
+    /// Uses the luminosity of the source with the hue and saturation of the destination.
+    HslLuminosity,
+}
+
+impl From<sys::hb_paint_composite_mode_t> for CompositeMode {
+    fn from(mode: sys::hb_paint_composite_mode_t) -> Self {
+        match mode {
+            sys::HB_PAINT_COMPOSITE_MODE_CLEAR => CompositeMode::Clear,
+            sys::HB_PAINT_COMPOSITE_MODE_SRC => CompositeMode::Src,
+            sys::HB_PAINT_COMPOSITE_MODE_DEST => CompositeMode::Dest,
+            sys::HB_PAINT_COMPOSITE_MODE_DEST_OVER => CompositeMode::DestOver,
+            sys::HB_PAINT_COMPOSITE_MODE_SRC_IN => CompositeMode::SrcIn,
+            sys::HB_PAINT_COMPOSITE_MODE_DEST_IN => CompositeMode::DestIn,
+            sys::HB_PAINT_COMPOSITE_MODE_SRC_OUT => CompositeMode::SrcOut,
+            sys::HB_PAINT_COMPOSITE_MODE_DEST_OUT => CompositeMode::DestOut,
+            sys::HB_PAINT_COMPOSITE_MODE_SRC_ATOP => CompositeMode::SrcAtop,
+            sys::HB_PAINT_COMPOSITE_MODE_DEST_ATOP => CompositeMode::DestAtop,
+            sys::HB_PAINT_COMPOSITE_MODE_XOR => CompositeMode::Xor,
+            sys::HB_PAINT_COMPOSITE_MODE_PLUS => CompositeMode::Plus,
+            sys::HB_PAINT_COMPOSITE_MODE_SCREEN => CompositeMode::Screen,
+            sys::HB_PAINT_COMPOSITE_MODE_OVERLAY => CompositeMode::Overlay,
+            sys::HB_PAINT_COMPOSITE_MODE_DARKEN => CompositeMode::Darken,
+            sys::HB_PAINT_COMPOSITE_MODE_LIGHTEN => CompositeMode::Lighten,
+            sys::HB_PAINT_COMPOSITE_MODE_COLOR_DODGE => CompositeMode::ColorDodge,
+            sys::HB_PAINT_COMPOSITE_MODE_COLOR_BURN => CompositeMode::ColorBurn,
+            sys::HB_PAINT_COMPOSITE_MODE_HARD_LIGHT => CompositeMode::HardLight,
+            sys::HB_PAINT_COMPOSITE_MODE_SOFT_LIGHT => CompositeMode::SoftLight,
+            sys::HB_PAINT_COMPOSITE_MODE_DIFFERENCE => CompositeMode::Difference,
+            sys::HB_PAINT_COMPOSITE_MODE_EXCLUSION => CompositeMode::Exclusion,
+            sys::HB_PAINT_COMPOSITE_MODE_MULTIPLY => CompositeMode::Multiply,
+            sys::HB_PAINT_COMPOSITE_MODE_HSL_HUE => CompositeMode::HslHue,
+            sys::HB_PAINT_COMPOSITE_MODE_HSL_SATURATION => CompositeMode::HslSaturation,
+            sys::HB_PAINT_COMPOSITE_MODE_HSL_COLOR => CompositeMode::HslColor,
+            sys::HB_PAINT_COMPOSITE_MODE_HSL_LUMINOSITY => CompositeMode::HslLuminosity,
+            _ => CompositeMode::SrcOver,
+        }
+    }
+}
+
+impl From<CompositeMode> for sys::hb_paint_composite_mode_t {
+    fn from(mode: CompositeMode) -> Self {
+        match mode {
+            CompositeMode::Clear => sys::HB_PAINT_COMPOSITE_MODE_CLEAR,
+            CompositeMode::Src => sys::HB_PAINT_COMPOSITE_MODE_SRC,
+            CompositeMode::Dest => sys::HB_PAINT_COMPOSITE_MODE_DEST,
+            CompositeMode::SrcOver => sys::HB_PAINT_COMPOSITE_MODE_SRC_OVER,
+            CompositeMode::DestOver => sys::HB_PAINT_COMPOSITE_MODE_DEST_OVER,
+            CompositeMode::SrcIn => sys::HB_PAINT_COMPOSITE_MODE_SRC_IN,
+            CompositeMode::DestIn => sys::HB_PAINT_COMPOSITE_MODE_DEST_IN,
+            CompositeMode::SrcOut => sys::HB_PAINT_COMPOSITE_MODE_SRC_OUT,
+            CompositeMode::DestOut => sys::HB_PAINT_COMPOSITE_MODE_DEST_OUT,
+            CompositeMode::SrcAtop => sys::HB_PAINT_COMPOSITE_MODE_SRC_ATOP,
+            CompositeMode::DestAtop => sys::HB_PAINT_COMPOSITE_MODE_DEST_ATOP,
+            CompositeMode::Xor => sys::HB_PAINT_COMPOSITE_MODE_XOR,
+            CompositeMode::Plus => sys::HB_PAINT_COMPOSITE_MODE_PLUS,
+            CompositeMode::Screen => sys::HB_PAINT_COMPOSITE_MODE_SCREEN,
+            CompositeMode::Overlay => sys::HB_PAINT_COMPOSITE_MODE_OVERLAY,
+            CompositeMode::Darken => sys::HB_PAINT_COMPOSITE_MODE_DARKEN,
+            CompositeMode::Lighten => sys::HB_PAINT_COMPOSITE_MODE_LIGHTEN,
+            CompositeMode::ColorDodge => sys::HB_PAINT_COMPOSITE_MODE_COLOR_DODGE,
+            CompositeMode::ColorBurn => sys::HB_PAINT_COMPOSITE_MODE_COLOR_BURN,
+            CompositeMode::HardLight => sys::HB_PAINT_COMPOSITE_MODE_HARD_LIGHT,
+            CompositeMode::SoftLight => sys::HB_PAINT_COMPOSITE_MODE_SOFT_LIGHT,
+            CompositeMode::Difference => sys::HB_PAINT_COMPOSITE_MODE_DIFFERENCE,
+            CompositeMode::Exclusion => sys::HB_PAINT_COMPOSITE_MODE_EXCLUSION,
+            CompositeMode::Multiply => sys::HB_PAINT_COMPOSITE_MODE_MULTIPLY,
+            CompositeMode::HslHue => sys::HB_PAINT_COMPOSITE_MODE_HSL_HUE,
+            CompositeMode::HslSaturation => sys::HB_PAINT_COMPOSITE_MODE_HSL_SATURATION,
+            CompositeMode::HslColor => sys::HB_PAINT_COMPOSITE_MODE_HSL_COLOR,
+            CompositeMode::HslLuminosity => sys::HB_PAINT_COMPOSITE_MODE_HSL_LUMINOSITY,
+        }
+    }
+}
+
+/// A `PaintSink` forwarding to HarfBuzz paint functions, the way the
+/// built-in font functions emit paint operations.
+pub(crate) struct HbPaintSink {
+    funcs: *mut sys::hb_paint_funcs_t,
+    data: *mut c_void,
+}
+
+impl HbPaintSink {
+    pub(crate) fn new(funcs: *mut sys::hb_paint_funcs_t, data: *mut c_void) -> Self {
+        HbPaintSink { funcs, data }
+    }
+}
+
+impl PaintSink for HbPaintSink {
+    fn push_transform(&mut self, xx: f32, yx: f32, xy: f32, yy: f32, dx: f32, dy: f32) {
+        unsafe { sys::hb_paint_push_transform(self.funcs, self.data, xx, yx, xy, yy, dx, dy) }
+    }
+
+    fn pop_transform(&mut self) {
+        unsafe { sys::hb_paint_pop_transform(self.funcs, self.data) }
+    }
+
+    fn push_clip_glyph(&mut self, glyph: u32, font: &Font) {
+        unsafe { sys::hb_paint_push_clip_glyph(self.funcs, self.data, glyph, font.as_ptr()) }
+    }
+
+    fn push_clip_rectangle(&mut self, x_min: f32, y_min: f32, x_max: f32, y_max: f32) {
+        unsafe { sys::hb_paint_push_clip_rectangle(self.funcs, self.data, x_min, y_min, x_max, y_max) }
+    }
+
+    fn pop_clip(&mut self) {
+        unsafe { sys::hb_paint_pop_clip(self.funcs, self.data) }
+    }
+
+    fn color(&mut self, is_foreground: bool, color: Color) {
+        unsafe { sys::hb_paint_color(self.funcs, self.data, is_foreground as sys::hb_bool_t, color.into()) }
+    }
+
+    fn image(&mut self, image: &PaintImage) -> bool {
+        // `hb_paint_image` does not report whether the image was painted,
+        // so report every image as painted, as documented on the trait.
+        let mut extents = image.extents.map(sys::hb_glyph_extents_t::from);
+        unsafe {
+            sys::hb_paint_image(
+                self.funcs,
+                self.data,
+                image.blob.as_ptr(),
+                image.width,
+                image.height,
+                image.format.into(),
+                image.slant,
+                extents.as_mut().map_or(ptr::null_mut(), |extents| extents as *mut _),
+            );
+        }
+        true
+    }
+
+    fn linear_gradient(&mut self, color_line: &ColorLine, p0: (f32, f32), p1: (f32, f32), p2: (f32, f32)) {
+        unsafe {
+            sys::hb_paint_linear_gradient(self.funcs, self.data, color_line.raw, p0.0, p0.1, p1.0, p1.1, p2.0, p2.1)
+        }
+    }
+
+    fn radial_gradient(&mut self, color_line: &ColorLine, c0: (f32, f32), r0: f32, c1: (f32, f32), r1: f32) {
+        unsafe { sys::hb_paint_radial_gradient(self.funcs, self.data, color_line.raw, c0.0, c0.1, r0, c1.0, c1.1, r1) }
+    }
+
+    fn sweep_gradient(&mut self, color_line: &ColorLine, center: (f32, f32), start_angle: f32, end_angle: f32) {
+        unsafe {
+            sys::hb_paint_sweep_gradient(
+                self.funcs,
+                self.data,
+                color_line.raw,
+                center.0,
+                center.1,
+                start_angle,
+                end_angle,
+            )
+        }
+    }
+
+    fn push_group(&mut self) {
+        unsafe { sys::hb_paint_push_group(self.funcs, self.data) }
+    }
+
+    fn pop_group(&mut self, mode: CompositeMode) {
+        unsafe { sys::hb_paint_pop_group(self.funcs, self.data, mode.into()) }
+    }
+
+    fn custom_palette_color(&mut self, color_index: u32) -> Option<Color> {
+        let mut color = 0;
+        unsafe { sys::hb_paint_custom_palette_color(self.funcs, self.data, color_index, &mut color) != 0 }
+            .then_some(color.into())
+    }
+}
+
+/// Paints `glyph` of the raw `font` into `sink`.
+pub(crate) fn paint_glyph(
+    font: *mut sys::hb_font_t,
+    glyph: u32,
+    sink: &mut dyn PaintSink,
+    palette_index: u32,
+    foreground: Color,
+) {
+    let mut sink = sink;
+    unsafe {
+        sys::hb_font_paint_glyph(
+            font,
+            glyph,
+            paint_funcs(),
+            &mut sink as *mut &mut dyn PaintSink as *mut c_void,
+            palette_index,
+            foreground.into(),
+        );
+    }
+}
+
+/// Shared paint functions forwarding to a `&mut dyn PaintSink`, passed by
+/// pointer as the paint data.
+fn paint_funcs() -> *mut sys::hb_paint_funcs_t {
+    struct PaintFuncs(*mut sys::hb_paint_funcs_t);
+    // The paint functions are immutable once created.
+    unsafe impl Send for PaintFuncs {}
+    unsafe impl Sync for PaintFuncs {}
+
+    static PAINT_FUNCS: OnceLock<PaintFuncs> = OnceLock::new();
+    PAINT_FUNCS
+        .get_or_init(|| unsafe {
+            let funcs = sys::hb_paint_funcs_create();
+            sys::hb_paint_funcs_set_push_transform_func(funcs, Some(push_transform), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_pop_transform_func(funcs, Some(pop_transform), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_push_clip_glyph_func(funcs, Some(push_clip_glyph), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_push_clip_rectangle_func(funcs, Some(push_clip_rectangle), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_pop_clip_func(funcs, Some(pop_clip), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_color_func(funcs, Some(color), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_image_func(funcs, Some(image), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_linear_gradient_func(funcs, Some(linear_gradient), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_radial_gradient_func(funcs, Some(radial_gradient), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_sweep_gradient_func(funcs, Some(sweep_gradient), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_push_group_func(funcs, Some(push_group), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_pop_group_func(funcs, Some(pop_group), ptr::null_mut(), None);
+            sys::hb_paint_funcs_set_custom_palette_color_func(
+                funcs,
+                Some(custom_palette_color),
+                ptr::null_mut(),
+                None,
+            );
+            sys::hb_paint_funcs_make_immutable(funcs);
+            PaintFuncs(funcs)
+        })
+        .0
+}
+
+unsafe fn sink<'a>(paint_data: *mut c_void) -> &'a mut dyn PaintSink {
+    *(paint_data as *mut &mut dyn PaintSink)
+}
+
+unsafe fn color_line<'a>(color_line: *mut sys::hb_color_line_t) -> ColorLine<'a> {
+    ColorLine {
+        raw: color_line,
+        phantom: PhantomData,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn push_transform(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    xx: f32,
+    yx: f32,
+    xy: f32,
+    yy: f32,
+    dx: f32,
+    dy: f32,
+    _user_data: *mut c_void,
+) {
+    sink(paint_data).push_transform(xx, yx, xy, yy, dx, dy);
+}
+
+unsafe extern "C" fn pop_transform(_funcs: *mut sys::hb_paint_funcs_t, paint_data: *mut c_void, _user_data: *mut c_void) {
+    sink(paint_data).pop_transform();
+}
+
+unsafe extern "C" fn push_clip_glyph(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    glyph: sys::hb_codepoint_t,
+    font: *mut sys::hb_font_t,
+    _user_data: *mut c_void,
+) {
+    sink(paint_data).push_clip_glyph(glyph, &borrow_font(font));
+}
+
+unsafe extern "C" fn push_clip_rectangle(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    x_min: f32,
+    y_min: f32,
+    x_max: f32,
+    y_max: f32,
+    _user_data: *mut c_void,
+) {
+    sink(paint_data).push_clip_rectangle(x_min, y_min, x_max, y_max);
+}
+
+unsafe extern "C" fn pop_clip(_funcs: *mut sys::hb_paint_funcs_t, paint_data: *mut c_void, _user_data: *mut c_void) {
+    sink(paint_data).pop_clip();
+}
+
+unsafe extern "C" fn color(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    is_foreground: sys::hb_bool_t,
+    color: sys::hb_color_t,
+    _user_data: *mut c_void,
+) {
+    sink(paint_data).color(is_foreground != 0, color.into());
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn image(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    image: *mut sys::hb_blob_t,
+    width: c_uint,
+    height: c_uint,
+    format: sys::hb_tag_t,
+    slant: f32,
+    extents: *mut sys::hb_glyph_extents_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let image = PaintImage {
+        blob: Blob::from_raw(sys::hb_blob_reference(image)),
+        width,
+        height,
+        format: format.into(),
+        slant,
+        extents: extents.as_ref().map(|extents| (*extents).into()),
+    };
+    sink(paint_data).image(&image) as sys::hb_bool_t
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn linear_gradient(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    line: *mut sys::hb_color_line_t,
+    x0: f32,
+    y0: f32,
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+    _user_data: *mut c_void,
+) {
+    sink(paint_data).linear_gradient(&color_line(line), (x0, y0), (x1, y1), (x2, y2));
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn radial_gradient(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    line: *mut sys::hb_color_line_t,
+    x0: f32,
+    y0: f32,
+    r0: f32,
+    x1: f32,
+    y1: f32,
+    r1: f32,
+    _user_data: *mut c_void,
+) {
+    sink(paint_data).radial_gradient(&color_line(line), (x0, y0), r0, (x1, y1), r1);
+}
+
+unsafe extern "C" fn sweep_gradient(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    line: *mut sys::hb_color_line_t,
+    x0: f32,
+    y0: f32,
+    start_angle: f32,
+    end_angle: f32,
+    _user_data: *mut c_void,
+) {
+    sink(paint_data).sweep_gradient(&color_line(line), (x0, y0), start_angle, end_angle);
+}
+
+unsafe extern "C" fn push_group(_funcs: *mut sys::hb_paint_funcs_t, paint_data: *mut c_void, _user_data: *mut c_void) {
+    sink(paint_data).push_group();
+}
+
+unsafe extern "C" fn pop_group(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    mode: sys::hb_paint_composite_mode_t,
+    _user_data: *mut c_void,
+) {
+    sink(paint_data).pop_group(mode.into());
+}
+
+unsafe extern "C" fn custom_palette_color(
+    _funcs: *mut sys::hb_paint_funcs_t,
+    paint_data: *mut c_void,
+    color_index: c_uint,
+    color: *mut sys::hb_color_t,
+    _user_data: *mut c_void,
+) -> sys::hb_bool_t {
+    let result = sink(paint_data).custom_palette_color(color_index);
+    *color = result.unwrap_or_default().into();
+    result.is_some() as sys::hb_bool_t
+}
@@ -0,0 +1,233 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ab_glyph_rasterizer::{point, Point, Rasterizer};
+
+use crate::draw::TransformSink;
+use crate::{Font, OutlinePath, PathCommand, ShapedBuffer};
+
+/// The largest number of pixels of an image rendered by [`render_glyph`]
+/// or [`render_run`], such as 8192 × 8192.
+///
+/// Larger images, from a huge font scale or a long run, are not rendered,
+/// rather than allocating gigabytes of memory.
+pub const MAX_IMAGE_PIXELS: usize = 1 << 26;
+
+/// An 8-bit grayscale image, such as the antialiased coverage of a glyph.
+///
+/// Pixel values range from 0 (not covered) to 255 (fully covered).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GrayImage {
+    /// The width of the image, in pixels.
+    pub width: u32,
+    /// The height of the image, in pixels.
+    pub height: u32,
+    /// The x position of the left edge of the image, relative to the
+    /// origin of the rendered glyph or run.
+    pub left: i32,
+    /// The y position of the top edge of the image, relative to the
+    /// origin of the rendered glyph or run, with the y axis pointing down.
+    pub top: i32,
+    /// The pixels of the image, row by row from the top.
+    pub data: Vec<u8>,
+}
+
+impl GrayImage {
+    /// Returns the value of the pixel at (`x`, `y`), counted from the top
+    /// left corner of the image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside the image.
+    pub fn get_pixel(&self, x: u32, y: u32) -> u8 {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        self.data[(y * self.width + x) as usize]
+    }
+}
+
+/// Rasterizes a glyph of `font` with antialiasing.
+///
+/// One unit of the font's scale maps to one pixel, so the scale should be
+/// set to the desired pixel size with [`Font::set_scale`]. The image is
+/// just large enough to hold the outline, and is empty for glyphs without
+/// one.
+///
+/// Returns `None` if the image would have more than [`MAX_IMAGE_PIXELS`]
+/// pixels.
+///
+/// ```
+/// # use harfbuzz::{render_glyph, Face, Font};
+/// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+/// let mut font = Font::new(&face);
+/// font.set_scale(100, 100);
+/// let image = render_glyph(&font, font.get_nominal_glyph('l').unwrap()).unwrap();
+/// assert!(image.width > 0 && image.height > 0);
+/// assert!(image.top < 0);
+/// assert_eq!(image.get_pixel(image.width / 2, image.height / 2), 255);
+///
+/// font.set_scale(100_000, 100_000);
+/// assert!(render_glyph(&font, font.get_nominal_glyph('l').unwrap()).is_none());
+/// ```
+///
+/// [`Font::set_scale`]: crate::Font::set_scale
+pub fn render_glyph(font: &Font, glyph: u32) -> Option<GrayImage> {
+    let mut outline = OutlinePath::new();
+    font.draw_glyph(glyph, &mut outline);
+    rasterize(&outline)
+}
+
+/// Rasterizes a shaped run with antialiasing.
+///
+/// Glyphs are positioned with the offsets and advances of the buffer,
+/// starting with the pen at the origin, and their coverage is
+/// accumulated into a single image. As with [`render_glyph`], one unit
+/// of the font's scale maps to one pixel, and `None` is returned if the
+/// image would have more than [`MAX_IMAGE_PIXELS`] pixels.
+///
+/// ```
+/// # use harfbuzz::{hb_shape, render_glyph, render_run, Buffer, Face, Font};
+/// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+/// let mut font = Font::new(&face);
+/// font.set_scale(32, 32);
+/// let mut buffer = Buffer::with("ab");
+/// buffer.guess_segment_properties();
+/// let shaped = hb_shape(&font, buffer, &[]);
+///
+/// let image = render_run(&font, &shaped).unwrap();
+/// let a = render_glyph(&font, shaped.get_glyph_infos()[0].index()).unwrap();
+/// assert_eq!(image.left, a.left);
+/// assert!(image.width > a.width);
+/// assert!(image.data.iter().any(|&coverage| coverage == 255));
+/// ```
+pub fn render_run(font: &Font, buffer: &ShapedBuffer) -> Option<GrayImage> {
+    let mut outline = OutlinePath::new();
+    let (mut pen_x, mut pen_y) = (0, 0);
+    for (info, position) in buffer.get_glyph_infos().iter().zip(buffer.get_glyph_positions()) {
+        let mut sink = TransformSink {
+            sink: &mut outline,
+            x_scale: 1.0,
+            y_scale: 1.0,
+            slant: 0.0,
+            dx: (pen_x + position.x_offset()) as f32,
+            dy: (pen_y + position.y_offset()) as f32,
+        };
+        font.draw_glyph(info.index(), &mut sink);
+        pen_x += position.x_advance();
+        pen_y += position.y_advance();
+    }
+    rasterize(&outline)
+}
+
+/// Rasterizes an outline in font coordinates, flipping the y axis.
+///
+/// Returns `None` if the image would have more than `MAX_IMAGE_PIXELS`
+/// pixels.
+fn rasterize(outline: &OutlinePath) -> Option<GrayImage> {
+    let points = outline.commands().iter().flat_map(|command| match *command {
+        PathCommand::MoveTo { x, y } | PathCommand::LineTo { x, y } => vec![(x, y)],
+        PathCommand::QuadTo {
+            control_x,
+            control_y,
+            x,
+            y,
+        } => vec![(control_x, control_y), (x, y)],
+        PathCommand::CubicTo {
+            control1_x,
+            control1_y,
+            control2_x,
+            control2_y,
+            x,
+            y,
+        } => vec![(control1_x, control1_y), (control2_x, control2_y), (x, y)],
+        PathCommand::ClosePath => vec![],
+    });
+    let Some((min_x, min_y, max_x, max_y)) = points.fold(None, |bounds, (x, y)| {
+        let (x, y) = (x, -y);
+        Some(match bounds {
+            Some((min_x, min_y, max_x, max_y)) => (f32::min(min_x, x), f32::min(min_y, y), f32::max(max_x, x), f32::max(max_y, y)),
+            None => (x, y, x, y),
+        })
+    }) else {
+        return Some(GrayImage::default());
+    };
+
+    let (left, top) = (min_x.floor(), min_y.floor());
+    // The casts saturate, so huge bounds fail the size check below.
+    let width = (max_x.ceil() - left) as u32;
+    let height = (max_y.ceil() - top) as u32;
+    let size = (width as usize).checked_mul(height as usize).filter(|&size| size <= MAX_IMAGE_PIXELS)?;
+    let to_pixel = |x: f32, y: f32| point(x - left, -y - top);
+
+    let mut rasterizer = Rasterizer::new(width as usize, height as usize);
+    let (mut start, mut current) = (point(0.0, 0.0), point(0.0, 0.0));
+    let close = |rasterizer: &mut Rasterizer, start: Point, current: Point| {
+        if start != current {
+            rasterizer.draw_line(current, start);
+        }
+    };
+    for command in outline.commands() {
+        match *command {
+            PathCommand::MoveTo { x, y } => {
+                // Contours must be closed to be filled.
+                close(&mut rasterizer, start, current);
+                start = to_pixel(x, y);
+                current = start;
+            }
+            PathCommand::LineTo { x, y } => {
+                let to = to_pixel(x, y);
+                rasterizer.draw_line(current, to);
+                current = to;
+            }
+            PathCommand::QuadTo {
+                control_x,
+                control_y,
+                x,
+                y,
+            } => {
+                let to = to_pixel(x, y);
+                rasterizer.draw_quad(current, to_pixel(control_x, control_y), to);
+                current = to;
+            }
+            PathCommand::CubicTo {
+                control1_x,
+                control1_y,
+                control2_x,
+                control2_y,
+                x,
+                y,
+            } => {
+                let to = to_pixel(x, y);
+                rasterizer.draw_cubic(
+                    current,
+                    to_pixel(control1_x, control1_y),
+                    to_pixel(control2_x, control2_y),
+                    to,
+                );
+                current = to;
+            }
+            PathCommand::ClosePath => {
+                close(&mut rasterizer, start, current);
+                current = start;
+            }
+        }
+    }
+    close(&mut rasterizer, start, current);
+
+    let mut data = vec![0; size];
+    rasterizer.for_each_pixel(|index, coverage| {
+        data[index] = (coverage.min(1.0) * 255.0).round() as u8;
+    });
+    Some(GrayImage {
+        width,
+        height,
+        left: left as i32,
+        top: top as i32,
+        data,
+    })
+}
@@ -0,0 +1,390 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::mem;
+use std::ops::RangeInclusive;
+use std::os::raw::c_uint;
+use sys;
+
+/// The value HarfBuzz uses for "no value" in set iteration and queries,
+/// `HB_SET_VALUE_INVALID`.
+const INVALID: u32 = u32::MAX;
+
+/// A set of integers, such as Unicode characters or glyph IDs.
+///
+/// This wraps `hb_set_t`, which many HarfBuzz APIs fill or consume.
+///
+/// ```
+/// # use harfbuzz::Set;
+/// let mut set: Set = [5, 1, 3].into_iter().collect();
+/// set.add_range(10, 12);
+/// set.del(3);
+/// assert_eq!(set.len(), 5);
+/// assert!(set.contains(11));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [1, 5, 10, 11, 12]);
+/// assert_eq!(set.iter().rev().collect::<Vec<_>>(), [12, 11, 10, 5, 1]);
+/// assert_eq!(set.ranges().collect::<Vec<_>>(), [1..=1, 5..=5, 10..=12]);
+/// assert_eq!((set.min(), set.max()), (Some(1), Some(12)));
+/// ```
+pub struct Set {
+    raw: *mut sys::hb_set_t,
+}
+
+impl Set {
+    /// Create a new, empty set.
+    pub fn new() -> Self {
+        unsafe { Set::from_raw(sys::hb_set_create()) }
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        unsafe { sys::hb_set_get_population(self.raw) as usize }
+    }
+
+    /// Returns true if the set has no elements.
+    pub fn is_empty(&self) -> bool {
+        unsafe { sys::hb_set_is_empty(self.raw) != 0 }
+    }
+
+    /// Returns true if the set contains `value`.
+    pub fn contains(&self, value: u32) -> bool {
+        unsafe { sys::hb_set_has(self.raw, value) != 0 }
+    }
+
+    /// Adds `value` to the set.
+    pub fn add(&mut self, value: u32) {
+        unsafe { sys::hb_set_add(self.raw, value) }
+    }
+
+    /// Adds the values from `first` to `last`, inclusive, to the set.
+    pub fn add_range(&mut self, first: u32, last: u32) {
+        unsafe { sys::hb_set_add_range(self.raw, first, last) }
+    }
+
+    /// Removes `value` from the set.
+    pub fn del(&mut self, value: u32) {
+        unsafe { sys::hb_set_del(self.raw, value) }
+    }
+
+    /// Removes the values from `first` to `last`, inclusive, from the set.
+    pub fn del_range(&mut self, first: u32, last: u32) {
+        unsafe { sys::hb_set_del_range(self.raw, first, last) }
+    }
+
+    /// Removes all elements from the set.
+    pub fn clear(&mut self) {
+        unsafe { sys::hb_set_clear(self.raw) }
+    }
+
+    /// Makes the set contain exactly the values it did not contain.
+    ///
+    /// ```
+    /// # use harfbuzz::Set;
+    /// let mut set: Set = [1, 2].into_iter().collect();
+    /// set.invert();
+    /// assert!(set.is_inverted());
+    /// assert!(!set.contains(1));
+    /// assert!(set.contains(3));
+    /// assert_eq!(set.min(), Some(0));
+    /// ```
+    pub fn invert(&mut self) {
+        unsafe { sys::hb_set_invert(self.raw) }
+    }
+
+    /// Returns true if the set has been inverted.
+    pub fn is_inverted(&self) -> bool {
+        unsafe { sys::hb_set_is_inverted(self.raw) != 0 }
+    }
+
+    /// Adds the elements of `other` to the set.
+    ///
+    /// ```
+    /// # use harfbuzz::Set;
+    /// let a: Set = [1, 2, 3].into_iter().collect();
+    /// let b: Set = [2, 3, 4].into_iter().collect();
+    ///
+    /// let mut set = a.clone();
+    /// set.union(&b);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    ///
+    /// let mut set = a.clone();
+    /// set.intersect(&b);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), [2, 3]);
+    ///
+    /// let mut set = a.clone();
+    /// set.subtract(&b);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), [1]);
+    ///
+    /// let mut set = a.clone();
+    /// set.symmetric_difference(&b);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), [1, 4]);
+    /// ```
+    pub fn union(&mut self, other: &Set) {
+        unsafe { sys::hb_set_union(self.raw, other.raw) }
+    }
+
+    /// Removes the elements that are not in `other` from the set.
+    pub fn intersect(&mut self, other: &Set) {
+        unsafe { sys::hb_set_intersect(self.raw, other.raw) }
+    }
+
+    /// Removes the elements of `other` from the set.
+    pub fn subtract(&mut self, other: &Set) {
+        unsafe { sys::hb_set_subtract(self.raw, other.raw) }
+    }
+
+    /// Makes the set contain the elements that are either in the set or
+    /// in `other`, but not in both.
+    pub fn symmetric_difference(&mut self, other: &Set) {
+        unsafe { sys::hb_set_symmetric_difference(self.raw, other.raw) }
+    }
+
+    /// Returns true if all elements of the set are in `larger`.
+    ///
+    /// ```
+    /// # use harfbuzz::Set;
+    /// let set: Set = [1, 2].into_iter().collect();
+    /// let larger: Set = [1, 2, 3].into_iter().collect();
+    /// assert!(set.is_subset(&larger));
+    /// assert!(!larger.is_subset(&set));
+    /// ```
+    pub fn is_subset(&self, larger: &Set) -> bool {
+        unsafe { sys::hb_set_is_subset(self.raw, larger.raw) != 0 }
+    }
+
+    /// Returns the smallest element of the set, if any.
+    pub fn min(&self) -> Option<u32> {
+        match unsafe { sys::hb_set_get_min(self.raw) } {
+            INVALID => None,
+            value => Some(value),
+        }
+    }
+
+    /// Returns the largest element of the set, if any.
+    pub fn max(&self) -> Option<u32> {
+        match unsafe { sys::hb_set_get_max(self.raw) } {
+            INVALID => None,
+            value => Some(value),
+        }
+    }
+
+    /// Iterates over the elements of the set, in ascending order.
+    pub fn iter(&self) -> SetIter<'_> {
+        SetIter {
+            set: self,
+            page: Vec::with_capacity(SetIter::PAGE_SIZE),
+            index: 0,
+            front: INVALID,
+            back: INVALID,
+            done: false,
+        }
+    }
+
+    /// Iterates over the runs of consecutive elements of the set, in
+    /// ascending order.
+    pub fn ranges(&self) -> SetRanges<'_> {
+        SetRanges {
+            set: self,
+            last: INVALID,
+            done: false,
+        }
+    }
+
+    /// Construct a `Set` from a raw pointer. Takes ownership of the set.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must point to a valid `hb_set_t`, whose ownership passes to
+    /// the returned `Set`.
+    pub unsafe fn from_raw(raw: *mut sys::hb_set_t) -> Self {
+        Set { raw }
+    }
+
+    /// Borrows a raw pointer to the set.
+    pub fn as_ptr(&self) -> *mut sys::hb_set_t {
+        self.raw
+    }
+
+    /// Gives up ownership and returns a raw pointer to the set.
+    pub fn into_raw(self) -> *mut sys::hb_set_t {
+        let raw = self.raw;
+        mem::forget(self);
+        raw
+    }
+}
+
+impl Default for Set {
+    /// Create a new, empty set.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Set {
+    /// Copy the set and its elements.
+    fn clone(&self) -> Self {
+        unsafe { Set::from_raw(sys::hb_set_copy(self.raw)) }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        unsafe { sys::hb_set_set(self.raw, source.raw) }
+    }
+}
+
+impl PartialEq for Set {
+    fn eq(&self, other: &Set) -> bool {
+        unsafe { sys::hb_set_is_equal(self.raw, other.raw) != 0 }
+    }
+}
+
+impl Eq for Set {}
+
+impl Hash for Set {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe { sys::hb_set_hash(self.raw) }.hash(state)
+    }
+}
+
+impl fmt::Debug for Set {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<u32> for Set {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Self {
+        let mut set = Set::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<u32> for Set {
+    fn extend<I: IntoIterator<Item = u32>>(&mut self, iter: I) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Set {
+    type Item = u32;
+    type IntoIter = SetIter<'a>;
+
+    fn into_iter(self) -> SetIter<'a> {
+        self.iter()
+    }
+}
+
+impl Drop for Set {
+    fn drop(&mut self) {
+        unsafe { sys::hb_set_destroy(self.raw) }
+    }
+}
+
+/// An iterator over the elements of a [`Set`].
+///
+/// See [`Set::iter`].
+pub struct SetIter<'a> {
+    set: &'a Set,
+    page: Vec<u32>,
+    index: usize,
+    /// The last element returned from the front, or `INVALID`.
+    front: u32,
+    /// The last element returned from the back, or `INVALID`.
+    back: u32,
+    done: bool,
+}
+
+impl<'a> SetIter<'a> {
+    /// The number of elements fetched at a time from the front.
+    const PAGE_SIZE: usize = 256;
+}
+
+impl<'a> Iterator for SetIter<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.done {
+            return None;
+        }
+        if self.index == self.page.len() {
+            let start = self.page.last().copied().unwrap_or(INVALID);
+            unsafe {
+                let count = sys::hb_set_next_many(
+                    self.set.raw,
+                    start,
+                    self.page.as_mut_ptr(),
+                    Self::PAGE_SIZE as c_uint,
+                );
+                self.page.set_len(count as usize);
+            }
+            self.index = 0;
+        }
+        match self.page.get(self.index) {
+            Some(&value) if self.back == INVALID || value < self.back => {
+                self.index += 1;
+                self.front = value;
+                Some(value)
+            }
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for SetIter<'a> {
+    fn next_back(&mut self) -> Option<u32> {
+        if self.done {
+            return None;
+        }
+        let mut value = self.back;
+        let found = unsafe { sys::hb_set_previous(self.set.raw, &mut value) != 0 };
+        if found && (self.front == INVALID || value > self.front) {
+            self.back = value;
+            Some(value)
+        } else {
+            self.done = true;
+            None
+        }
+    }
+}
+
+/// An iterator over the runs of consecutive elements of a [`Set`].
+///
+/// See [`Set::ranges`].
+pub struct SetRanges<'a> {
+    set: &'a Set,
+    /// The end of the last range returned, or `INVALID`.
+    last: u32,
+    done: bool,
+}
+
+impl<'a> Iterator for SetRanges<'a> {
+    type Item = RangeInclusive<u32>;
+
+    fn next(&mut self) -> Option<RangeInclusive<u32>> {
+        if self.done {
+            return None;
+        }
+        let mut first = INVALID;
+        if unsafe { sys::hb_set_next_range(self.set.raw, &mut first, &mut self.last) } != 0 {
+            Some(first..=self.last)
+        } else {
+            // HarfBuzz resets the cursor, which would start over.
+            self.done = true;
+            None
+        }
+    }
+}
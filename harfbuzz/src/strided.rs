@@ -0,0 +1,210 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::marker::PhantomData;
+
+/// A read-only view of one field in each element of a slice.
+///
+/// Batched `Font` queries such as
+/// [`get_nominal_glyphs_strided`](crate::Font::get_nominal_glyphs_strided)
+/// read their input through a `Strided`, so it can live inside an array
+/// of the caller's own structs.
+///
+/// ```
+/// # use harfbuzz::Strided;
+/// struct Cell {
+///     c: char,
+///     glyph: u32,
+/// }
+///
+/// let cells = [Cell { c: 'a', glyph: 0 }, Cell { c: 'b', glyph: 0 }];
+/// let chars = Strided::new(&cells, |cell| &cell.c);
+/// assert_eq!(chars.len(), 2);
+///
+/// let contiguous = Strided::from(&['a', 'b'][..]);
+/// assert_eq!(contiguous.len(), 2);
+/// ```
+#[derive(Clone, Copy)]
+pub struct Strided<'a, T> {
+    ptr: *const T,
+    len: usize,
+    stride: usize,
+    phantom: PhantomData<&'a [T]>,
+}
+
+impl<'a, T> Strided<'a, T> {
+    /// Creates a view of the field returned by `field` in each element of
+    /// `items`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field` does not return a reference to a part of the
+    /// element it is given, at the same offset for every element.
+    pub fn new<S>(items: &'a [S], field: impl Fn(&S) -> &T) -> Self {
+        let offset = field_offset::<S, T>(
+            items.iter().map(|item| (item as *const S as usize, field(item) as *const T as usize)),
+        );
+        Strided {
+            ptr: unsafe { (items.as_ptr() as *const u8).add(offset) } as *const T,
+            len: items.len(),
+            stride: size_of::<S>(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at `index`, if in bounds.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        (index < self.len).then(|| unsafe { &*((self.ptr as *const u8).add(index * self.stride) as *const T) })
+    }
+
+    /// Iterates over the elements of the view.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let (ptr, stride) = (self.ptr as *const u8, self.stride);
+        (0..self.len).map(move |index| unsafe { &*(ptr.add(index * stride) as *const T) })
+    }
+
+    /// Creates a view of `len` elements, the first at `ptr` and the others
+    /// each `stride` bytes after the previous one.
+    pub(crate) unsafe fn from_raw_parts(ptr: *const T, len: usize, stride: usize) -> Self {
+        Strided {
+            ptr,
+            len,
+            stride,
+            phantom: PhantomData,
+        }
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    pub(crate) fn stride(&self) -> usize {
+        self.stride
+    }
+}
+
+impl<'a, T> From<&'a [T]> for Strided<'a, T> {
+    fn from(items: &'a [T]) -> Self {
+        Strided {
+            ptr: items.as_ptr(),
+            len: items.len(),
+            stride: size_of::<T>(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// A mutable view of one field in each element of a slice.
+///
+/// Batched `Font` queries such as
+/// [`get_glyph_h_advances_strided`](crate::Font::get_glyph_h_advances_strided)
+/// write their output through a `StridedMut`, so it can be stored
+/// directly into an array of the caller's own structs.
+pub struct StridedMut<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    stride: usize,
+    phantom: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T> StridedMut<'a, T> {
+    /// Creates a mutable view of the field returned by `field` in each
+    /// element of `items`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field` does not return a reference to a part of the
+    /// element it is given, at the same offset for every element.
+    pub fn new<S>(items: &'a mut [S], mut field: impl FnMut(&mut S) -> &mut T) -> Self {
+        let offset = field_offset::<S, T>(
+            items.iter_mut().map(|item| (item as *mut S as usize, field(item) as *mut T as usize)),
+        );
+        StridedMut {
+            ptr: unsafe { (items.as_mut_ptr() as *mut u8).add(offset) } as *mut T,
+            len: items.len(),
+            stride: size_of::<S>(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a mutable reference to the element at `index`, if in
+    /// bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (index < self.len).then(|| unsafe { &mut *((self.ptr as *mut u8).add(index * self.stride) as *mut T) })
+    }
+
+    /// Creates a mutable view of `len` elements, the first at `ptr` and
+    /// the others each `stride` bytes after the previous one.
+    pub(crate) unsafe fn from_raw_parts(ptr: *mut T, len: usize, stride: usize) -> Self {
+        StridedMut {
+            ptr,
+            len,
+            stride,
+            phantom: PhantomData,
+        }
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    pub(crate) fn stride(&self) -> usize {
+        self.stride
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for StridedMut<'a, T> {
+    fn from(items: &'a mut [T]) -> Self {
+        StridedMut {
+            ptr: items.as_mut_ptr(),
+            len: items.len(),
+            stride: size_of::<T>(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Computes the offset of a field within elements of type `S`, given the
+/// addresses of each element of a slice and of the field within it.
+fn field_offset<S, T>(fields: impl Iterator<Item = (usize, usize)>) -> usize {
+    let mut offset = None;
+    for (element, field) in fields {
+        let field_offset = field.wrapping_sub(element);
+        assert!(
+            field >= element && field_offset + size_of::<T>() <= size_of::<S>(),
+            "field must be part of the element"
+        );
+        assert!(
+            offset.unwrap_or(field_offset) == field_offset,
+            "field must be at the same offset in every element"
+        );
+        offset = Some(field_offset);
+    }
+    offset.unwrap_or(0)
+}
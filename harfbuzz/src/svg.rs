@@ -0,0 +1,174 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt::Write;
+
+use crate::{Font, OutlinePath, PathCommand, ShapedBuffer};
+
+/// How [`shaped_buffer_to_svg`] emits glyph outlines.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SvgPaths {
+    /// One `<path>` element per glyph, in buffer order.
+    PerGlyph,
+    /// A single `<path>` element with the outlines of all glyphs.
+    Merged,
+}
+
+/// Renders a shaped run as an SVG document.
+///
+/// Each glyph is drawn with [`Font::draw_glyph`] and positioned with the
+/// offsets and advances of the buffer, starting with the pen at the
+/// origin. The offsets HarfBuzz produces already account for the glyph
+/// origin of the buffer's direction, so right-to-left runs extend to the
+/// right in visual order and vertical runs extend downwards. The
+/// coordinates are in the font's scale, with the y axis flipped to point
+/// down as in SVG, and the view box fits the outlines of the run.
+///
+/// ```
+/// # use harfbuzz::{hb_shape, shaped_buffer_to_svg, Buffer, Direction, Face, Font, SvgPaths};
+/// let face = Face::new_from_file("../harfbuzz-sys/harfbuzz/test/api/fonts/SourceSansPro-Regular.otf", 0).unwrap();
+/// let font = Font::new(&face);
+/// let mut buffer = Buffer::with("ab");
+/// buffer.guess_segment_properties();
+/// let shaped = hb_shape(&font, buffer, &[]);
+///
+/// let svg = shaped_buffer_to_svg(&font, &shaped, SvgPaths::PerGlyph);
+/// assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox="));
+/// assert_eq!(svg.matches("<path ").count(), 2);
+///
+/// let svg = shaped_buffer_to_svg(&font, &shaped, SvgPaths::Merged);
+/// assert_eq!(svg.matches("<path ").count(), 1);
+///
+/// // The first point of each glyph's outline, with the y axis flipped.
+/// let starts = |svg: &str| -> Vec<String> {
+///     svg.split("<path d=\"M").skip(1).map(|path| path.split(' ').take(2).collect::<Vec<_>>().join(" ")).collect()
+/// };
+///
+/// // The "b" outline starts at (297, -12), and the "a" one at (194, -12).
+/// // Right to left, the "b" comes first in visual order, and the pen
+/// // moves right by its advance of 539 to the "a".
+/// let mut buffer = Buffer::with("ab");
+/// buffer.set_direction(Direction::RTL);
+/// let shaped = hb_shape(&font, buffer, &[]);
+/// let svg = shaped_buffer_to_svg(&font, &shaped, SvgPaths::PerGlyph);
+/// assert_eq!(starts(&svg), ["297 12", "733 12"]);
+///
+/// // Top to bottom, the offsets move each glyph from its vertical origin,
+/// // and the pen moves down by the advance of 1257.
+/// let mut buffer = Buffer::with("ab");
+/// buffer.set_direction(Direction::TTB);
+/// let shaped = hb_shape(&font, buffer, &[]);
+/// let svg = shaped_buffer_to_svg(&font, &shaped, SvgPaths::PerGlyph);
+/// assert_eq!(starts(&svg), ["-58 996", "21 2253"]);
+/// ```
+///
+/// [`Font::draw_glyph`]: crate::Font::draw_glyph
+pub fn shaped_buffer_to_svg(font: &Font, buffer: &ShapedBuffer, paths: SvgPaths) -> String {
+    let mut bounds = Bounds::default();
+    let mut elements = String::new();
+    let mut merged = String::new();
+    let mut outline = OutlinePath::new();
+    let (mut pen_x, mut pen_y) = (0, 0);
+    for (info, position) in buffer.get_glyph_infos().iter().zip(buffer.get_glyph_positions()) {
+        let glyph = info.index();
+        let x = (pen_x + position.x_offset()) as f32;
+        let y = (pen_y + position.y_offset()) as f32;
+        pen_x += position.x_advance();
+        pen_y += position.y_advance();
+
+        outline.clear();
+        font.draw_glyph(glyph, &mut outline);
+        if outline.is_empty() {
+            continue;
+        }
+        match paths {
+            SvgPaths::PerGlyph => {
+                let mut data = String::new();
+                write_path_data(&mut data, &outline, x, y, &mut bounds);
+                let _ = write!(elements, "<path d=\"{}\"/>", data);
+            }
+            SvgPaths::Merged => write_path_data(&mut merged, &outline, x, y, &mut bounds),
+        }
+    }
+    if !merged.is_empty() {
+        let _ = write!(elements, "<path d=\"{}\"/>", merged);
+    }
+
+    let (min_x, min_y, max_x, max_y) = bounds.0.unwrap_or_default();
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">{}</svg>",
+        min_x,
+        min_y,
+        max_x - min_x,
+        max_y - min_y,
+        elements
+    )
+}
+
+/// The bounding box of the points of a path, in SVG coordinates.
+#[derive(Default)]
+struct Bounds(Option<(f32, f32, f32, f32)>);
+
+impl Bounds {
+    fn add(&mut self, x: f32, y: f32) {
+        self.0 = Some(match self.0 {
+            Some((min_x, min_y, max_x, max_y)) => (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)),
+            None => (x, y, x, y),
+        });
+    }
+}
+
+/// Appends the SVG path data of `outline`, translated by (`dx`, `dy`) in
+/// font coordinates, to `data`.
+fn write_path_data(data: &mut String, outline: &OutlinePath, dx: f32, dy: f32, bounds: &mut Bounds) {
+    let mut point = |x: f32, y: f32| {
+        let (x, y) = (x + dx, -(y + dy));
+        bounds.add(x, y);
+        (x, y)
+    };
+    for command in outline.commands() {
+        if !data.is_empty() {
+            data.push(' ');
+        }
+        let _ = match *command {
+            PathCommand::MoveTo { x, y } => {
+                let (x, y) = point(x, y);
+                write!(data, "M{} {}", x, y)
+            }
+            PathCommand::LineTo { x, y } => {
+                let (x, y) = point(x, y);
+                write!(data, "L{} {}", x, y)
+            }
+            PathCommand::QuadTo {
+                control_x,
+                control_y,
+                x,
+                y,
+            } => {
+                let (control_x, control_y) = point(control_x, control_y);
+                let (x, y) = point(x, y);
+                write!(data, "Q{} {} {} {}", control_x, control_y, x, y)
+            }
+            PathCommand::CubicTo {
+                control1_x,
+                control1_y,
+                control2_x,
+                control2_y,
+                x,
+                y,
+            } => {
+                let (control1_x, control1_y) = point(control1_x, control1_y);
+                let (control2_x, control2_y) = point(control2_x, control2_y);
+                let (x, y) = point(x, y);
+                write!(data, "C{} {} {} {} {} {}", control1_x, control1_y, control2_x, control2_y, x, y)
+            }
+            PathCommand::ClosePath => write!(data, "Z"),
+        };
+    }
+}
@@ -10,6 +10,7 @@
 use sys;
 
 /// Compact representation of features, scripts and languages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Tag {
     tag: u32,
 }
@@ -0,0 +1,147 @@
+// Copyright 2018 The Servo Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use bitflags::bitflags;
+use std::os::raw::{c_char, c_int, c_uint};
+use std::{ffi, fmt, str};
+use sys;
+
+use crate::{NameId, Tag};
+
+/// Data type for holding variation data, i.e. the value of a variation
+/// axis of a variable font.
+///
+/// Variations can be parsed from and formatted to strings in the format
+/// understood by HarfBuzz, e.g. `wght=500`:
+///
+/// ```
+/// # use harfbuzz::{Tag, Variation};
+/// let variation: Variation = "wght=500".parse().unwrap();
+/// assert_eq!(variation.tag(), Tag::from_string("wght"));
+/// assert_eq!(variation.value(), 500.0);
+/// assert_eq!(variation.to_string(), "wght=500");
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Variation {
+    hb_variation: sys::hb_variation_t,
+}
+
+impl Variation {
+    /// Creates a variation setting the axis `tag` to `value`, in design
+    /// (user-space) units.
+    pub fn new(tag: Tag, value: f32) -> Variation {
+        Self { hb_variation: sys::hb_variation_t { tag: tag.into(), value } }
+    }
+
+    /// The tag of the variation axis.
+    pub fn tag(&self) -> Tag {
+        self.hb_variation.tag.into()
+    }
+
+    /// The value of the variation axis.
+    pub fn value(&self) -> f32 {
+        self.hb_variation.value
+    }
+}
+
+impl str::FromStr for Variation {
+    type Err = super::ErrorKind;
+
+    /// Parses a string into a `Variation`, using `hb_variation_from_string`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut hb_variation = sys::hb_variation_t { tag: 0, value: 0.0 };
+        let ok = unsafe {
+            sys::hb_variation_from_string(s.as_ptr() as *const c_char, s.len() as c_int, &mut hb_variation)
+        };
+        if ok != 0 {
+            Ok(Variation { hb_variation })
+        } else {
+            Err(super::ErrorKind::HbFailure)
+        }
+    }
+}
+
+impl fmt::Display for Variation {
+    /// Formats the variation using `hb_variation_to_string`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // HarfBuzz never writes more than 128 bytes, including the nul.
+        let mut buf: [c_char; 128] = [0; 128];
+        let mut hb_variation = self.hb_variation;
+        let s = unsafe {
+            sys::hb_variation_to_string(&mut hb_variation, buf.as_mut_ptr(), buf.len() as c_uint);
+            ffi::CStr::from_ptr(buf.as_ptr())
+        };
+        f.write_str(&s.to_string_lossy())
+    }
+}
+
+/// A named instance of a variable font, such as "Bold Condensed".
+///
+/// See [`Face::get_named_instance`](crate::Face::get_named_instance).
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedInstance {
+    /// The index of the instance in the font's `fvar` table.
+    pub index: u32,
+    /// The `name` table ID of the instance's subfamily name.
+    pub subfamily_name_id: NameId,
+    /// The English subfamily name of the instance, if present.
+    pub subfamily_name: Option<String>,
+    /// The `name` table ID of the instance's PostScript name, if any.
+    pub postscript_name_id: Option<NameId>,
+    /// The English PostScript name of the instance, if present.
+    pub postscript_name: Option<String>,
+    /// The design coordinates of the instance, in the order of the axes
+    /// in the `fvar` table.
+    pub coords: Vec<f32>,
+}
+
+/// Information about a variation axis of a variable font.
+///
+/// See [`Face::variation_axes`](crate::Face::variation_axes).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AxisInfo {
+    /// The index of the axis in the font's `fvar` table.
+    pub index: u32,
+    /// The tag of the axis, such as `wght`.
+    pub tag: Tag,
+    /// The `name` table ID of the axis name.
+    pub name_id: NameId,
+    /// The flags of the axis.
+    pub flags: AxisFlags,
+    /// The minimum value of the axis.
+    pub min_value: f32,
+    /// The default value of the axis.
+    pub default_value: f32,
+    /// The maximum value of the axis.
+    pub max_value: f32,
+}
+
+impl From<sys::hb_ot_var_axis_info_t> for AxisInfo {
+    fn from(info: sys::hb_ot_var_axis_info_t) -> Self {
+        AxisInfo {
+            index: info.axis_index,
+            tag: info.tag.into(),
+            name_id: info.name_id.into(),
+            flags: AxisFlags::from_bits_retain(info.flags),
+            min_value: info.min_value,
+            default_value: info.default_value,
+            max_value: info.max_value,
+        }
+    }
+}
+
+bitflags! {
+    /// Flags of a variation axis.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct AxisFlags: u32 {
+        /// The axis should not be exposed directly in user interfaces.
+        const HIDDEN = sys::HB_OT_VAR_AXIS_FLAG_HIDDEN;
+    }
+}